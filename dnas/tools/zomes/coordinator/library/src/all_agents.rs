@@ -1,14 +1,12 @@
 use hdk::prelude::*;
 use library_integrity::*;
+
+use crate::index_shard::get_index_links;
 #[hdk_extern]
 pub fn get_all_agents(_: ()) -> ExternResult<Vec<AgentPubKey>> {
-    let path = Path::from("all_agents");
-    let links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAgents)?.build(),
-    )?;
+    let links = get_index_links("all_agents", LinkTypes::AllAgents)?;
     Ok(links
         .into_iter()
-        .map(|l| l.target.into_agent_pub_key())
-        .filter_map(|l| l)
+        .filter_map(|l| l.target.into_agent_pub_key())
         .collect())
 }
@@ -1,21 +1,19 @@
 use hdk::prelude::*;
 use library_integrity::*;
+
+use crate::index_shard::{get_index_links, get_index_links_for_page};
+use crate::pagination::{Page, PageInput};
 #[hdk_extern]
 pub fn get_all_developer_collective_links(_: ()) -> ExternResult<Vec<Link>> {
-    let path = Path::from("all_developer_collectives");
-    get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllDeveloperCollectives)?
-            .build(),
+    get_index_links(
+        "all_developer_collectives",
+        LinkTypes::AllDeveloperCollectives,
     )
 }
 
 #[hdk_extern]
 pub fn get_all_original_developer_collectives(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_developer_collectives");
-    let links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllDeveloperCollectives)?
-            .build(),
-    )?;
+    let links = get_all_developer_collective_links(())?;
     let get_input: Vec<GetInput> = links
         .into_iter()
         .map(|link| {
@@ -33,3 +31,14 @@ pub fn get_all_original_developer_collectives(_: ()) -> ExternResult<Vec<Record>
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
     Ok(records.into_iter().flatten().collect())
 }
+
+/// Gets a page of the original records of all DeveloperCollectives
+#[hdk_extern]
+pub fn get_all_original_developer_collectives_page(page: PageInput) -> ExternResult<Page<Record>> {
+    let links = get_index_links_for_page(
+        "all_developer_collectives",
+        LinkTypes::AllDeveloperCollectives,
+        &page,
+    )?;
+    page.page_of_link_targets(links)
+}
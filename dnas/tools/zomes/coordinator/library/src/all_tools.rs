@@ -0,0 +1,80 @@
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::index_shard::{get_index_links, get_index_links_for_page};
+use crate::pagination::{Page, PageInput};
+use crate::tool::get_latest_tool;
+#[hdk_extern]
+pub fn get_all_tool_links(_: ()) -> ExternResult<Vec<Link>> {
+    get_index_links("all_tools", LinkTypes::AllTools)
+}
+
+/// Gets the original records of all Tools of all DeveloperCollectives
+#[hdk_extern]
+pub fn get_all_tools(_: ()) -> ExternResult<Vec<Record>> {
+    let links = get_all_tool_links(())?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
+
+/// Gets the latest revision of all Tools of all DeveloperCollectives
+#[hdk_extern]
+pub fn get_all_latest_tools(_: ()) -> ExternResult<Vec<Record>> {
+    let mut records = vec![];
+    for link in get_all_tool_links(())? {
+        let original_tool_hash =
+            link.target
+                .into_action_hash()
+                .ok_or(wasm_error!(WasmErrorInner::Guest(
+                    "No action hash associated with link".to_string()
+                )))?;
+        if let Some(record) = get_latest_tool(original_tool_hash)? {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Gets a page of the original records of all Tools of all DeveloperCollectives
+#[hdk_extern]
+pub fn get_all_tools_page(page: PageInput) -> ExternResult<Page<Record>> {
+    let links = get_index_links_for_page("all_tools", LinkTypes::AllTools, &page)?;
+    page.page_of_link_targets(links)
+}
+
+/// Gets a page of the latest revisions of all Tools of all DeveloperCollectives
+#[hdk_extern]
+pub fn get_all_latest_tools_page(page: PageInput) -> ExternResult<Page<Record>> {
+    let links = get_index_links_for_page("all_tools", LinkTypes::AllTools, &page)?;
+    let links_page = page.page_of_links(links);
+    let mut records = vec![];
+    for link in links_page.items {
+        let original_tool_hash =
+            link.target
+                .into_action_hash()
+                .ok_or(wasm_error!(WasmErrorInner::Guest(
+                    "No action hash associated with link".to_string()
+                )))?;
+        if let Some(record) = get_latest_tool(original_tool_hash)? {
+            records.push(record);
+        }
+    }
+    Ok(Page {
+        items: records,
+        next: links_page.next,
+    })
+}
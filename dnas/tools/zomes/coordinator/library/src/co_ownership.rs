@@ -0,0 +1,105 @@
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::contributor_permission::create_permission_revocation;
+use crate::ownership_transfer::get_owner_permissions;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddCoOwnerInput {
+    pub developer_collective_hash: ActionHash,
+    pub agent: AgentPubKey,
+}
+/// Appoints an agent as co-owner of a DeveloperCollective. The co-owner needs to call
+/// `accept_co_ownership` before they can exercise their owner rights.
+#[hdk_extern]
+pub fn add_co_owner(input: AddCoOwnerInput) -> ExternResult<Record> {
+    let co_ownership_hash = create_entry(&EntryTypes::CoOwnership(CoOwnership {
+        for_collective: input.developer_collective_hash.clone(),
+        for_agent: input.agent.clone(),
+    }))?;
+    create_link(
+        input.developer_collective_hash,
+        co_ownership_hash.clone(),
+        LinkTypes::DeveloperCollectiveToCoOwners,
+        // We also add a tag to the link for which agent the co-ownership is
+        LinkTag::new(input.agent.get_raw_39()),
+    )?;
+    let record = get(co_ownership_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created CoOwnership".to_string())
+    ))?;
+    Ok(record)
+}
+/// Accepts a co-ownership by linking the DeveloperCollective from the agent's own public key
+#[hdk_extern]
+pub fn accept_co_ownership(co_ownership_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(co_ownership_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("CoOwnership not found".to_string())
+    ))?;
+    let co_ownership = co_ownership_from_record(&record)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Record does not contain a CoOwnership entry".to_string())
+    ))?;
+    create_link(
+        co_ownership.for_agent,
+        co_ownership.for_collective,
+        LinkTypes::OwnerToDeveloperCollective,
+        // Tag must contain the CoOwnership action hash here:
+        LinkTag::new(co_ownership_hash.get_raw_39()),
+    )
+}
+/// Removes a co-owner by revoking their CoOwnership and returns the action hash of the
+/// PermissionRevocation. Can be called by an owner of the DeveloperCollective or by the co-owner
+/// themselves. Their owner link is removed as well, by the co-owner when they acknowledge the
+/// revocation of another owner.
+#[hdk_extern]
+pub fn remove_co_owner(co_ownership_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(co_ownership_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("CoOwnership not found".to_string())
+    ))?;
+    let co_ownership = co_ownership_from_record(&record)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Record does not contain a CoOwnership entry".to_string())
+    ))?;
+    let revocation_hash = create_permission_revocation(co_ownership_hash)?;
+    if agent_info()?.agent_initial_pubkey == co_ownership.for_agent {
+        delete_my_owner_links(&co_ownership.for_collective)?;
+    }
+    Ok(revocation_hash)
+}
+/// Deletes the OwnerToDeveloperCollective links from the calling agent to the collective
+pub(crate) fn delete_my_owner_links(developer_collective_hash: &ActionHash) -> ExternResult<()> {
+    let owner_links = get_links(
+        GetLinksInputBuilder::try_new(
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::OwnerToDeveloperCollective,
+        )?
+        .build(),
+    )?;
+    for link in owner_links {
+        if link.target.into_action_hash().as_ref() == Some(developer_collective_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+#[hdk_extern]
+pub fn get_co_ownerships_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(
+            developer_collective_hash,
+            LinkTypes::DeveloperCollectiveToCoOwners,
+        )?
+        .build(),
+    )
+}
+/// Gets the current owners of the DeveloperCollective: its creator and co-owners that have not
+/// been removed, taking accepted ownership transfers into account
+#[hdk_extern]
+pub fn get_owners_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<Vec<AgentPubKey>> {
+    Ok(get_owner_permissions(developer_collective_hash)?
+        .into_iter()
+        .map(|(owner, _)| owner)
+        .collect())
+}
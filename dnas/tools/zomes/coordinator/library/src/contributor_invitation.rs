@@ -0,0 +1,212 @@
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::contributor_permission::{
+    create_contributor_permission, create_permission_revocation,
+    get_contributor_permissions_for_contributor, get_permission_revocations,
+    revoke_contributor_permission,
+};
+use crate::pagination::{Page, PageInput};
+use crate::RemoteSignal;
+
+/// Invites an agent to contribute to a DeveloperCollective. The invited agent is notified with
+/// a remote signal and needs to call `accept_invitation` before they can act on behalf of the
+/// collective.
+#[hdk_extern]
+pub fn invite_contributor(contributor_invitation: ContributorInvitation) -> ExternResult<Record> {
+    let contributor_invitation_hash = create_entry(&EntryTypes::ContributorInvitation(
+        contributor_invitation.clone(),
+    ))?;
+    create_link(
+        contributor_invitation.for_collective.clone(),
+        contributor_invitation_hash.clone(),
+        LinkTypes::DeveloperCollectiveToContributorInvitations,
+        // We also add a tag to the link for which agent the invitation is
+        LinkTag::new(contributor_invitation.for_agent.get_raw_39()),
+    )?;
+    create_link(
+        contributor_invitation.for_agent.clone(),
+        contributor_invitation_hash.clone(),
+        LinkTypes::InviteeToContributorInvitations,
+        // We also add a tag to the link for which collective the invitation is
+        LinkTag::new(contributor_invitation.for_collective.get_raw_39()),
+    )?;
+    let record = get(contributor_invitation_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(
+            "Could not find the newly created ContributorInvitation".to_string()
+        )),
+    )?;
+    // The invitation is on the DHT either way, so an offline invitee shouldn't make this fail
+    if let Err(err) = send_remote_signal(
+        RemoteSignal::InvitationReceived {
+            contributor_invitation_hash,
+        },
+        vec![contributor_invitation.for_agent],
+    ) {
+        error!("Error signaling the invitation to the invitee: {:?}", err);
+    }
+    Ok(record)
+}
+/// Accepts an invitation by creating the ContributorPermission it offers
+#[hdk_extern]
+pub fn accept_invitation(contributor_invitation_hash: ActionHash) -> ExternResult<Record> {
+    let contributor_invitation = get_open_invitation(contributor_invitation_hash.clone())?;
+    if contributor_invitation.for_agent != agent_info()?.agent_initial_pubkey {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the invited agent can accept an invitation".to_string()
+        )));
+    }
+    let record = create_contributor_permission(ContributorPermission {
+        for_collective: contributor_invitation.for_collective.clone(),
+        for_agent: contributor_invitation.for_agent.clone(),
+        expiry: contributor_invitation.expiry,
+        role: contributor_invitation.role,
+        tools: contributor_invitation.tools.clone(),
+        invitation: Some(contributor_invitation_hash.clone()),
+        renews: None,
+    })?;
+    delete_invitation_links(&contributor_invitation, &contributor_invitation_hash)?;
+    Ok(record)
+}
+/// Declines an invitation for the calling agent
+#[hdk_extern]
+pub fn decline_invitation(contributor_invitation_hash: ActionHash) -> ExternResult<ActionHash> {
+    let contributor_invitation = get_open_invitation(contributor_invitation_hash.clone())?;
+    delete_invitation_links(&contributor_invitation, &contributor_invitation_hash)?;
+    delete_entry(contributor_invitation_hash)
+}
+/// Withdraws an invitation by creating a PermissionRevocation for it and returns the action hash
+/// of the revocation. Only owners of the DeveloperCollective can do this. Validation cannot see
+/// the withdrawal when the invitee accepts the invitation, so a permission that has already
+/// been accepted with it is revoked as well.
+#[hdk_extern]
+pub fn withdraw_invitation(contributor_invitation_hash: ActionHash) -> ExternResult<ActionHash> {
+    let contributor_invitation = get_open_invitation(contributor_invitation_hash.clone())?;
+    let revocation_hash = create_permission_revocation(contributor_invitation_hash.clone())?;
+    delete_invitation_links(&contributor_invitation, &contributor_invitation_hash)?;
+    let links = get_contributor_permissions_for_contributor(contributor_invitation.for_agent)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter(|link| link.tag.0 == contributor_invitation.for_collective.get_raw_39())
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|permission_hash| GetInput::new(permission_hash.into(), GetOptions::default()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    for record in records.into_iter().flatten() {
+        if let Some(EntryTypes::ContributorPermission(contributor_permission)) =
+            app_entry_from_record(&record)?
+        {
+            if contributor_permission.invitation.as_ref() == Some(&contributor_invitation_hash)
+                && get_permission_revocations(record.action_address().clone())?.is_empty()
+            {
+                revoke_contributor_permission(record.action_address().clone())?;
+            }
+        }
+    }
+    Ok(revocation_hash)
+}
+/// Gets the invitations for the calling agent that have neither been accepted nor declined yet
+#[hdk_extern]
+pub fn get_pending_invitations(_: ()) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::InviteeToContributorInvitations,
+        )?
+        .build(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
+/// Gets a page of the invitations for the calling agent that are still pending
+#[hdk_extern]
+pub fn get_pending_invitations_page(page: PageInput) -> ExternResult<Page<Record>> {
+    let links = get_links(page.links_input(GetLinksInputBuilder::try_new(
+        agent_info()?.agent_initial_pubkey,
+        LinkTypes::InviteeToContributorInvitations,
+    )?))?;
+    page.page_of_link_targets(links)
+}
+#[hdk_extern]
+pub fn get_invitations_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(
+            developer_collective_hash,
+            LinkTypes::DeveloperCollectiveToContributorInvitations,
+        )?
+        .build(),
+    )
+}
+/// Withdraws every open invitation to a DeveloperCollective. Used when deleting the collective.
+pub(crate) fn withdraw_invitations_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<()> {
+    for link in get_invitations_for_developer_collective(developer_collective_hash)? {
+        let Some(contributor_invitation_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        withdraw_invitation(contributor_invitation_hash)?;
+    }
+    Ok(())
+}
+
+/// Gets an invitation that has not been declined or withdrawn
+fn get_open_invitation(
+    contributor_invitation_hash: ActionHash,
+) -> ExternResult<ContributorInvitation> {
+    let Some(Details::Record(details)) =
+        get_details(contributor_invitation_hash.clone(), GetOptions::default())?
+    else {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "ContributorInvitation not found".to_string()
+        )));
+    };
+    if !details.deletes.is_empty()
+        || !get_permission_revocations(contributor_invitation_hash.clone())?.is_empty()
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "ContributorInvitation has been declined or withdrawn".to_string()
+        )));
+    }
+    contributor_invitation_from_record(&details.record)?.ok_or(wasm_error!(WasmErrorInner::Guest(
+        "Record does not contain a ContributorInvitation entry".to_string()
+    )))
+}
+
+fn delete_invitation_links(
+    contributor_invitation: &ContributorInvitation,
+    contributor_invitation_hash: &ActionHash,
+) -> ExternResult<()> {
+    let links =
+        get_invitations_for_developer_collective(contributor_invitation.for_collective.clone())?
+            .into_iter()
+            .chain(get_links(
+                GetLinksInputBuilder::try_new(
+                    contributor_invitation.for_agent.clone(),
+                    LinkTypes::InviteeToContributorInvitations,
+                )?
+                .build(),
+            )?);
+    for link in links {
+        if link.target.into_action_hash().as_ref() == Some(contributor_invitation_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,11 @@
+use crate::co_ownership::delete_my_owner_links;
+use crate::ownership_transfer::get_owner_permissions;
+use crate::pagination::{Page, PageInput};
 use hdk::prelude::*;
 use library_integrity::*;
-#[hdk_extern]
-pub fn create_contributor_permission(
+/// Creates a ContributorPermission with its links. Permissions are created by the contributor
+/// when accepting a ContributorInvitation, see `accept_invitation`.
+pub(crate) fn create_contributor_permission(
     contributor_permission: ContributorPermission,
 ) -> ExternResult<Record> {
     let contributor_permission_hash = create_entry(&EntryTypes::ContributorPermission(
@@ -92,17 +96,408 @@ pub fn get_all_contributor_permissions(
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
     Ok(records.into_iter().flatten().collect())
 }
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetAllContributorPermissionsPageInput {
+    pub developer_collective_hash: ActionHash,
+    pub page: PageInput,
+}
+/// Gets a page of the ContributorPermissions for a DeveloperCollective, including revoked ones
+#[hdk_extern]
+pub fn get_all_contributor_permissions_page(
+    input: GetAllContributorPermissionsPageInput,
+) -> ExternResult<Page<Record>> {
+    let links = get_links(input.page.links_input(GetLinksInputBuilder::try_new(
+        input.developer_collective_hash,
+        LinkTypes::DeveloperCollectiveToContributorPermissions,
+    )?))?;
+    input.page.page_of_link_targets(links)
+}
 
-/// Gets the least restrictive permission status for a developer collective
+/// Revokes a ContributorPermission by creating a PermissionRevocation for it. Only owners of
+/// the developer collective can do this.
+#[hdk_extern]
+pub fn revoke_contributor_permission(
+    contributor_permission_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+    create_permission_revocation(contributor_permission_hash)
+}
+/// Creates a PermissionRevocation for a ContributorPermission or CoOwnership and links it from
+/// the revoked permission. The revoked agent is notified so that they acknowledge it.
+pub(crate) fn create_permission_revocation(
+    permission_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+    let revocation_hash = create_entry(&EntryTypes::PermissionRevocation(PermissionRevocation {
+        permission: permission_hash.clone(),
+    }))?;
+    create_link(
+        permission_hash.clone(),
+        revocation_hash.clone(),
+        LinkTypes::PermissionRevocations,
+        (),
+    )?;
+    notify_revoked_agent(permission_hash, revocation_hash.clone())?;
+    Ok(revocation_hash)
+}
+/// Asks the agent whose permission has been revoked to acknowledge the revocation on their own
+/// source chain, where validation can see it. The revocation stands either way, so an
+/// unreachable agent doesn't make this fail.
+fn notify_revoked_agent(
+    permission_hash: ActionHash,
+    revocation_hash: ActionHash,
+) -> ExternResult<()> {
+    let Some(permission_record) = get(permission_hash, GetOptions::default())? else {
+        return Ok(());
+    };
+    let revoked_agent = match app_entry_from_record(&permission_record)? {
+        Some(EntryTypes::ContributorPermission(contributor_permission)) => {
+            contributor_permission.for_agent
+        }
+        Some(EntryTypes::CoOwnership(co_ownership)) => co_ownership.for_agent,
+        _ => return Ok(()),
+    };
+    if revoked_agent == agent_info()?.agent_initial_pubkey {
+        return Ok(());
+    }
+    let revocation_record = get(revocation_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created PermissionRevocation".to_string())
+    ))?;
+    match call_remote(
+        revoked_agent,
+        zome_info()?.name,
+        "recv_permission_revocation".into(),
+        None,
+        revocation_record,
+    ) {
+        Ok(ZomeCallResponse::Ok(_)) => (),
+        response => error!("Error notifying the revoked agent: {:?}", response),
+    }
+    Ok(())
+}
+/// Acknowledges the revocation of one of our ContributorPermissions or of our CoOwnership by
+/// linking it from our own public key, with the first permission of the renewal chain or the
+/// CoOwnership in the tag. Validation rejects actions with any permission of that chain, or as
+/// co-owner, from then on. Our owner links are removed as well. Called remotely by the owner
+/// that revoked the permission, see `create_permission_revocation`.
+#[hdk_extern]
+pub fn recv_permission_revocation(revocation_record: Record) -> ExternResult<()> {
+    let revoker = revocation_record.action().author().clone();
+    if call_info()?.provenance != revoker {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the author of a PermissionRevocation can notify about it".to_string()
+        )));
+    }
+    verify_signed_record(&revocation_record)?;
+    let permission_revocation = permission_revocation_from_record(&revocation_record)?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(
+            "Record does not contain a PermissionRevocation entry".to_string()
+        )),
+    )?;
+    let permission_record =
+        get(permission_revocation.permission, GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("Revoked permission not found".to_string())
+        ))?;
+    let (for_collective, for_agent, grant_hash) = match app_entry_from_record(&permission_record)? {
+        Some(EntryTypes::ContributorPermission(contributor_permission)) => (
+            contributor_permission.for_collective,
+            contributor_permission.for_agent,
+            renewal_chain_root(&permission_record)?
+                .action_address()
+                .clone(),
+        ),
+        Some(EntryTypes::CoOwnership(co_ownership)) => (
+            co_ownership.for_collective,
+            co_ownership.for_agent,
+            permission_record.action_address().clone(),
+        ),
+        _ => {
+            return Err(wasm_error!(WasmErrorInner::Guest(
+                "Only revocations of ContributorPermissions and CoOwnerships can be acknowledged"
+                    .to_string()
+            )))
+        }
+    };
+    if for_agent != agent_info()?.agent_initial_pubkey {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The revoked permission is not ours".to_string()
+        )));
+    }
+    if !get_owner_permissions(for_collective.clone())?
+        .iter()
+        .any(|(owner, _)| owner == &revoker)
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only owners of the developer collective can revoke permissions for it.".to_string()
+        )));
+    }
+    acknowledge_revocation(grant_hash, revocation_record.action_address().clone())?;
+    if co_ownership_from_record(&permission_record)?.is_some() {
+        delete_my_owner_links(&for_collective)?;
+    }
+    Ok(())
+}
+/// Links the revocation of the grant from our own public key, unless we have already
+/// acknowledged a revocation of it
+fn acknowledge_revocation(grant_hash: ActionHash, revocation_hash: ActionHash) -> ExternResult<()> {
+    let my_agent = agent_info()?.agent_initial_pubkey;
+    let acknowledgements = get_links(
+        GetLinksInputBuilder::try_new(my_agent.clone(), LinkTypes::RevocationAcknowledgements)?
+            .build(),
+    )?;
+    if acknowledgements
+        .iter()
+        .any(|link| link.tag.0 == grant_hash.get_raw_39())
+    {
+        return Ok(());
+    }
+    create_link(
+        my_agent,
+        revocation_hash,
+        LinkTypes::RevocationAcknowledgements,
+        LinkTag::new(grant_hash.get_raw_39()),
+    )?;
+    Ok(())
+}
+/// Verifies that a record received from another agent is signed by its author and that its
+/// entry belongs to its action
+fn verify_signed_record(record: &Record) -> ExternResult<()> {
+    let action = record.action().clone();
+    let entry_matches = match (action.entry_hash(), record.entry().as_option()) {
+        (Some(entry_hash), Some(entry)) => &hash_entry(entry.clone())? == entry_hash,
+        _ => false,
+    };
+    if !entry_matches
+        || &hash_action(action.clone())? != record.action_address()
+        || !verify_signature(action.author().clone(), record.signature().clone(), action)?
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Record is not signed by its author".to_string()
+        )));
+    }
+    Ok(())
+}
+/// Revokes every ContributorPermission for a DeveloperCollective that has not been revoked yet.
+/// Used when deleting the collective.
+pub(crate) fn revoke_contributor_permissions_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<()> {
+    for link in get_contributor_permissions_for_developer_collective(developer_collective_hash)? {
+        let Some(contributor_permission_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        if get_contributor_permission_revocation(contributor_permission_hash.clone())?.is_none() {
+            revoke_contributor_permission(contributor_permission_hash)?;
+        }
+    }
+    Ok(())
+}
+/// Deletes the links from a DeveloperCollective and from its contributors to their
+/// ContributorPermissions. Validation only allows this after the collective has been deleted.
+pub(crate) fn delete_contributor_permission_links(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<()> {
+    for link in
+        get_contributor_permissions_for_developer_collective(developer_collective_hash.clone())?
+    {
+        if let Ok(contributor) = AgentPubKey::from_raw_39(link.tag.0) {
+            for contributor_link in get_contributor_permissions_for_contributor(contributor)? {
+                if contributor_link.target == link.target {
+                    delete_link(contributor_link.create_link_hash)?;
+                }
+            }
+        }
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RenewContributorPermissionInput {
+    pub previous_permission_hash: ActionHash,
+    pub expiry: Option<Timestamp>,
+}
+/// Renews a ContributorPermission by issuing a new permission with a later expiry that is
+/// chained to the previous one. Can only be called by owners of the developer collective.
+/// Neither the previous permission nor any permission it renews may have been revoked.
+#[hdk_extern]
+pub fn renew_contributor_permission(
+    input: RenewContributorPermissionInput,
+) -> ExternResult<Record> {
+    let previous_record = get(
+        input.previous_permission_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(
+        "ContributorPermission not found".to_string()
+    )))?;
+    let previous_permission: ContributorPermission = previous_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Record does not contain a ContributorPermission entry".to_string()
+        )))?;
+    ensure_permission_not_revoked(input.previous_permission_hash.clone())?;
+    let mut renewed_permission_hash = previous_permission.renews.clone();
+    while let Some(permission_hash) = renewed_permission_hash {
+        ensure_permission_not_revoked(permission_hash.clone())?;
+        renewed_permission_hash = get(permission_hash, GetOptions::default())?
+            .and_then(|record| record.entry().to_app_option::<ContributorPermission>().ok())
+            .flatten()
+            .and_then(|permission| permission.renews);
+    }
+    let record = create_contributor_permission(ContributorPermission {
+        expiry: input.expiry,
+        invitation: None,
+        renews: Some(input.previous_permission_hash.clone()),
+        ..previous_permission
+    })?;
+    create_link(
+        input.previous_permission_hash,
+        record.action_address().clone(),
+        LinkTypes::ContributorPermissionRenewals,
+        (),
+    )?;
+    Ok(record)
+}
+#[hdk_extern]
+pub fn get_contributor_permission_renewals(
+    contributor_permission_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(
+            contributor_permission_hash,
+            LinkTypes::ContributorPermissionRenewals,
+        )?
+        .build(),
+    )
+}
+/// Follows the renewals of a ContributorPermission to the most recent one
+#[hdk_extern]
+pub fn get_latest_contributor_permission(
+    contributor_permission_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+    let mut latest_permission_hash = contributor_permission_hash;
+    loop {
+        let renewals = get_contributor_permission_renewals(latest_permission_hash.clone())?;
+        let latest_renewal = renewals
+            .into_iter()
+            .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+        match latest_renewal.and_then(|link| link.target.into_action_hash()) {
+            Some(renewal_hash) => latest_permission_hash = renewal_hash,
+            None => break,
+        }
+    }
+    get(latest_permission_hash, GetOptions::default())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetExpiringPermissionsInput {
+    pub developer_collective_hash: ActionHash,
+    pub within_days: u32,
+}
+/// Gets the current ContributorPermissions for a developer collective that expire within the
+/// given number of days and have neither been renewed nor revoked yet, so that owners can be
+/// warned to renew them
+#[hdk_extern]
+pub fn get_expiring_permissions(input: GetExpiringPermissionsInput) -> ExternResult<Vec<Record>> {
+    let now = sys_time()?;
+    let deadline = Timestamp::from_micros(
+        now.as_micros()
+            .saturating_add(i64::from(input.within_days) * 86_400_000_000),
+    );
+    let expiring_permissions: Vec<Record> =
+        get_all_contributor_permissions(input.developer_collective_hash)?
+            .into_iter()
+            .filter(|record| {
+                record
+                    .entry()
+                    .to_app_option::<ContributorPermission>()
+                    .ok()
+                    .flatten()
+                    .and_then(|permission| permission.expiry)
+                    .is_some_and(|expiry| expiry >= now && expiry <= deadline)
+            })
+            .collect();
+    // Fetch the renewals and revocations of all expiring permissions at once
+    let get_links_input: Vec<GetLinksInput> = expiring_permissions
+        .iter()
+        .flat_map(|record| {
+            [
+                LinkTypes::ContributorPermissionRenewals,
+                LinkTypes::PermissionRevocations,
+            ]
+            .map(|link_type| {
+                Ok(
+                    GetLinksInputBuilder::try_new(record.action_address().clone(), link_type)?
+                        .build(),
+                )
+            })
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links = HDK.with(|hdk| hdk.borrow().get_links(get_links_input))?;
+    Ok(expiring_permissions
+        .into_iter()
+        .zip(links.chunks(2))
+        .filter(|(_, links)| links.iter().all(|links| links.is_empty()))
+        .map(|(record, _)| record)
+        .collect())
+}
+
+#[hdk_extern]
+pub fn get_permission_revocations(permission_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(permission_hash, LinkTypes::PermissionRevocations)?.build(),
+    )
+}
+/// Gets the oldest PermissionRevocation of a ContributorPermission, i.e. the moment from which
+/// on it is revoked
+#[hdk_extern]
+pub fn get_contributor_permission_revocation(
+    contributor_permission_hash: ActionHash,
+) -> ExternResult<Option<SignedActionHashed>> {
+    let get_input: Vec<GetInput> = get_permission_revocations(contributor_permission_hash)?
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|revocation_hash| GetInput::new(revocation_hash.into(), GetOptions::default()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records
+        .into_iter()
+        .flatten()
+        .map(|record| record.signed_action)
+        .min_by_key(|revocation| revocation.action().timestamp()))
+}
+
+/// Errors if the given permission hash refers to a ContributorPermission that has been revoked.
+/// Owner permissions are not ContributorPermissions and are left to validation.
+pub(crate) fn ensure_permission_not_revoked(permission_hash: ActionHash) -> ExternResult<()> {
+    let Some(record) = get(permission_hash.clone(), GetOptions::default())? else {
+        return Ok(());
+    };
+    if !matches!(
+        app_entry_from_record(&record)?,
+        Some(EntryTypes::ContributorPermission(_))
+    ) {
+        return Ok(());
+    }
+    match get_contributor_permission_revocation(permission_hash)? {
+        Some(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "ContributorPermission has been revoked.".to_string()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Gets the least restrictive permission for a developer collective
 #[hdk_extern]
 pub fn get_my_permission(
     developer_collective_hash: ActionHash,
 ) -> ExternResult<Option<ActionHash>> {
     let agent = agent_info()?.agent_initial_pubkey;
-    return get_agent_permission(GetAgentPermissionInput {
+    get_agent_permission(GetAgentPermissionInput {
         developer_collective_hash,
         agent,
-    });
+    })
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -113,91 +508,206 @@ pub struct GetAgentPermissionInput {
 
 #[hdk_extern]
 pub fn get_agent_permission(input: GetAgentPermissionInput) -> ExternResult<Option<ActionHash>> {
-    let developer_collective_record = get(
+    let status = get_agent_permission_status(GetAgentPermissionStatusInput {
+        developer_collective_hash: input.developer_collective_hash,
+        agent: input.agent,
+        at: None,
+    })?;
+    Ok(status.permission_hash)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GetAgentPermissionStatusInput {
+    pub developer_collective_hash: ActionHash,
+    pub agent: AgentPubKey,
+    /// Point in time to evaluate the permissions at. Defaults to the current system time.
+    pub at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AccessLevel {
+    Owner,
+    Contributor,
+    NoAccess,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GrantState {
+    Active,
+    Expired,
+    Revoked,
+    /// Superseded by a renewal
+    Renewed,
+}
+
+/// A ContributorPermission held by an agent together with its state
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PermissionGrant {
+    pub permission_hash: ActionHash,
+    pub permission: ContributorPermission,
+    pub state: GrantState,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AgentPermissionStatus {
+    pub access_level: AccessLevel,
+    /// The action hash to reference as permission_hash when acting on behalf of the
+    /// developer collective, if the agent is allowed to
+    pub permission_hash: Option<ActionHash>,
+    pub role: Option<ContributorRole>,
+    pub tools: Option<Vec<ActionHash>>,
+    pub expiry: Option<Timestamp>,
+    /// Microseconds until the permission expires
+    pub time_remaining: Option<i64>,
+    /// All ContributorPermissions ever issued to the agent for the developer collective
+    pub grants: Vec<PermissionGrant>,
+    pub evaluated_at: Timestamp,
+}
+
+/// Explains whether and why an agent can act on behalf of a developer collective. Owners get
+/// their owner permission, contributors the least restrictive of their active permissions, i.e.
+/// one without expiry or otherwise the one that expires last.
+#[hdk_extern]
+pub fn get_agent_permission_status(
+    input: GetAgentPermissionStatusInput,
+) -> ExternResult<AgentPermissionStatus> {
+    let evaluated_at = match input.at {
+        Some(at) => at,
+        None => sys_time()?,
+    };
+    let grants = get_permission_grants(
         input.developer_collective_hash.clone(),
-        GetOptions::default(),
+        &input.agent,
+        evaluated_at,
     )?;
-    match developer_collective_record {
-        Some(r) => {
-            if r.action().author() == &input.agent {
-                // We have full Creator permission on that DeveloperCollective
-                return Ok(Some(r.action_address().clone()));
-            }
-            // Get all permission entries for the DeveloperCollective and find the ones that
-            // are for me, if any
-            let permission_links = get_contributor_permissions_for_developer_collective(
-                input.developer_collective_hash,
-            )?;
-
-            let links_to_my_permissions = permission_links
-                .into_iter()
-                .filter_map(|link| {
-                    let maybe_permitted_agent = AgentPubKey::from_raw_39(link.tag.0.clone()).ok();
-                    if let Some(permitted_agent) = maybe_permitted_agent {
-                        if permitted_agent == input.agent {
-                            return Some(link);
-                        }
-                    }
-                    None
-                })
-                .collect::<Vec<Link>>();
-
-            match links_to_my_permissions.len() {
-                0 => Ok(None),
-                _ => {
-                    // Get each permission and check whether it is expired
-                    let mut expiring_permissions = Vec::new();
-                    for link in links_to_my_permissions {
-                        let maybe_permission_action_hash = ActionHash::try_from(link.target).ok();
-                        if let Some(permission_action_hash) = maybe_permission_action_hash {
-                            let maybe_permission_record =
-                                get(permission_action_hash, GetOptions::default())?;
-                            if let Some(permission_record) = maybe_permission_record {
-                                match permission_record
-                                    .entry()
-                                    .to_app_option::<crate::ContributorPermission>()
-                                {
-                                    Ok(maybe_permission) => {
-                                        if let Some(permission) = maybe_permission {
-                                            match permission.expiry {
-                                                None => {
-                                                    return Ok(Some(
-                                                        permission_record.action_address().clone(),
-                                                    ))
-                                                }
-                                                Some(expiry) => {
-                                                    expiring_permissions.push((
-                                                        expiry,
-                                                        permission_record.action_address().clone(),
-                                                    ));
-                                                }
-                                            }
-                                        }
-                                    }
-                                    Err(_e) => (),
-                                }
-                            }
-                        }
-                    }
-
-                    let max_expiry_permission = expiring_permissions
-                        .into_iter()
-                        .max_by(|a, b| a.0.cmp(&b.0));
-
-                    match max_expiry_permission {
-                        Some((expiry, action_hash)) => {
-                            let now = sys_time()?;
-                            if now > expiry {
-                                Ok(None)
-                            } else {
-                                Ok(Some(action_hash))
-                            }
-                        }
-                        None => Ok(None),
-                    }
-                }
-            }
-        }
-        None => Ok(None),
+
+    // The creator, co-owners and recipients of accepted ownership transfers have full owner
+    // permissions
+    let owner_permission = get_owner_permissions(input.developer_collective_hash)?
+        .into_iter()
+        .find(|(owner, _)| owner == &input.agent);
+    if let Some((_, owner_permission_hash)) = owner_permission {
+        return Ok(AgentPermissionStatus {
+            access_level: AccessLevel::Owner,
+            permission_hash: Some(owner_permission_hash),
+            role: None,
+            tools: None,
+            expiry: None,
+            time_remaining: None,
+            grants,
+            evaluated_at,
+        });
     }
+
+    let best_grant = grants
+        .iter()
+        .filter(|grant| grant.state == GrantState::Active)
+        .max_by_key(|grant| match grant.permission.expiry {
+            None => i64::MAX,
+            Some(expiry) => expiry.as_micros(),
+        })
+        .cloned();
+    match best_grant {
+        Some(grant) => Ok(AgentPermissionStatus {
+            access_level: AccessLevel::Contributor,
+            permission_hash: Some(grant.permission_hash),
+            role: Some(grant.permission.role),
+            tools: grant.permission.tools,
+            expiry: grant.permission.expiry,
+            time_remaining: grant
+                .permission
+                .expiry
+                .map(|expiry| expiry.as_micros() - evaluated_at.as_micros()),
+            grants,
+            evaluated_at,
+        }),
+        None => Ok(AgentPermissionStatus {
+            access_level: AccessLevel::NoAccess,
+            permission_hash: None,
+            role: None,
+            tools: None,
+            expiry: None,
+            time_remaining: None,
+            grants,
+            evaluated_at,
+        }),
+    }
+}
+
+/// Gets all ContributorPermissions of the agent for the developer collective and their
+/// revocations with one batched fetch each and determines their state at the given time
+fn get_permission_grants(
+    developer_collective_hash: ActionHash,
+    agent: &AgentPubKey,
+    at: Timestamp,
+) -> ExternResult<Vec<PermissionGrant>> {
+    let permission_hashes: Vec<ActionHash> =
+        get_contributor_permissions_for_developer_collective(developer_collective_hash)?
+            .into_iter()
+            .filter(|link| {
+                AgentPubKey::from_raw_39(link.tag.0.clone()).ok().as_ref() == Some(agent)
+            })
+            .filter_map(|link| link.target.into_action_hash())
+            .collect();
+    let mut permissions = Vec::new();
+    for (record, revocations) in get_permissions_with_revocations(permission_hashes)? {
+        let Some(record) = record else {
+            continue;
+        };
+        let Ok(Some(permission)) = record.entry().to_app_option::<ContributorPermission>() else {
+            continue;
+        };
+        permissions.push((
+            record.action_address().clone(),
+            permission,
+            !revocations.is_empty(),
+        ));
+    }
+
+    let renewed_permission_hashes: Vec<ActionHash> = permissions
+        .iter()
+        .filter_map(|(_, permission, _)| permission.renews.clone())
+        .collect();
+    Ok(permissions
+        .into_iter()
+        .map(|(permission_hash, permission, revoked)| {
+            let state = if revoked {
+                GrantState::Revoked
+            } else if renewed_permission_hashes.contains(&permission_hash) {
+                GrantState::Renewed
+            } else if permission.expiry.is_some_and(|expiry| expiry < at) {
+                GrantState::Expired
+            } else {
+                GrantState::Active
+            };
+            PermissionGrant {
+                permission_hash,
+                permission,
+                state,
+            }
+        })
+        .collect())
+}
+
+/// Gets the records of the given ContributorPermissions and the links to their revocations,
+/// with one batched fetch each
+fn get_permissions_with_revocations(
+    permission_hashes: Vec<ActionHash>,
+) -> ExternResult<Vec<(Option<Record>, Vec<Link>)>> {
+    let get_input: Vec<GetInput> = permission_hashes
+        .iter()
+        .map(|permission_hash| GetInput::new(permission_hash.clone().into(), GetOptions::default()))
+        .collect();
+    let get_links_input: Vec<GetLinksInput> = permission_hashes
+        .into_iter()
+        .map(|permission_hash| {
+            Ok(
+                GetLinksInputBuilder::try_new(permission_hash, LinkTypes::PermissionRevocations)?
+                    .build(),
+            )
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let revocations = HDK.with(|hdk| hdk.borrow().get_links(get_links_input))?;
+    Ok(records.into_iter().zip(revocations).collect())
 }
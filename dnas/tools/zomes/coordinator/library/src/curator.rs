@@ -1,17 +1,17 @@
 use hdk::prelude::*;
 use library_integrity::*;
+
+use crate::index_shard::{create_index_link, delete_index_links};
 #[hdk_extern]
 pub fn create_curator(curator: Curator) -> ExternResult<Record> {
     let curator_hash = create_entry(&EntryTypes::Curator(curator.clone()))?;
     let record = get(curator_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly created Curator".to_string())
     ))?;
-    let path = Path::from("all_curators");
-    create_link(
-        path.path_entry_hash()?,
-        curator_hash.clone(),
+    create_index_link(
+        "all_curators",
+        curator_hash.clone().into(),
         LinkTypes::AllCurators,
-        (),
     )?;
     Ok(record)
 }
@@ -112,17 +112,11 @@ pub fn delete_curator(original_curator_hash: ActionHash) -> ExternResult<ActionH
             "Malformed get details response".to_string()
         ))),
     }?;
-    let path = Path::from("all_curators");
-    let links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllCurators)?.build(),
+    delete_index_links(
+        "all_curators",
+        &original_curator_hash.clone().into(),
+        LinkTypes::AllCurators,
     )?;
-    for link in links {
-        if let Some(hash) = link.target.into_action_hash() {
-            if hash.eq(&original_curator_hash) {
-                delete_link(link.create_link_hash)?;
-            }
-        }
-    }
     delete_entry(original_curator_hash)
 }
 #[hdk_extern]
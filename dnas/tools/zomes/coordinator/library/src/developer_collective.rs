@@ -1,5 +1,21 @@
 use hdk::prelude::*;
 use library_integrity::*;
+
+use crate::co_ownership::get_co_ownerships_for_developer_collective;
+use crate::contributor_invitation::withdraw_invitations_for_developer_collective;
+use crate::contributor_permission::{
+    delete_contributor_permission_links, get_my_permission,
+    revoke_contributor_permissions_for_developer_collective,
+};
+use crate::curator_to_developer_collectives::get_curators_for_developer_collective;
+use crate::icon::migrate_icon;
+use crate::index_shard::{create_index_link, delete_index_links};
+use crate::ownership_transfer::get_ownership_transfers_for_developer_collective;
+use crate::pagination::{Page, PageInput};
+use crate::tool::{
+    delete_tool, get_latest_tool, get_tool_links_for_developer_collective, update_tool,
+    UpdateToolInput, UpdatedTool,
+};
 #[hdk_extern]
 pub fn create_developer_collective(
     developer_collective: DeveloperCollective,
@@ -7,12 +23,10 @@ pub fn create_developer_collective(
     let developer_collective_hash = create_entry(&EntryTypes::DeveloperCollective(
         developer_collective.clone(),
     ))?;
-    let path = Path::from("all_developer_collectives");
-    create_link(
-        path.path_entry_hash()?,
-        developer_collective_hash.clone(),
+    create_index_link(
+        "all_developer_collectives",
+        developer_collective_hash.clone().into(),
         LinkTypes::AllDeveloperCollectives,
-        (),
     )?;
     create_link(
         agent_info()?.agent_initial_pubkey,
@@ -132,50 +146,97 @@ pub fn update_developer_collective(input: UpdateDeveloperCollectiveInput) -> Ext
     )))?;
     Ok(record)
 }
+/// Deletes a DeveloperCollective together with every link pointing to it, see
+/// `delete_developer_collective_with_links`. Collectives that still have Tools can't be deleted
+/// this way, since that would leave the Tools orphaned. Use `delete_developer_collective_cascade`
+/// to delete or deprecate them along with the collective.
 #[hdk_extern]
 pub fn delete_developer_collective(
     original_developer_collective_hash: ActionHash,
 ) -> ExternResult<ActionHash> {
-    let details = get_details(
-        original_developer_collective_hash.clone(),
-        GetOptions::default(),
-    )?
-    .ok_or(wasm_error!(WasmErrorInner::Guest(
-        "{pascal_entry_def_name} not found".to_string()
-    )))?;
-    let _record = match details {
-        Details::Record(details) => Ok(details.record),
-        _ => Err(wasm_error!(WasmErrorInner::Guest(
-            "Malformed get details response".to_string()
-        ))),
-    }?;
-    let path = Path::from("all_developer_collectives");
-    let all_developer_collective_links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllDeveloperCollectives)?
-            .build(),
-    )?;
-    for link in all_developer_collective_links {
-        if let Some(hash) = link.target.into_action_hash() {
-            if hash.eq(&original_developer_collective_hash) {
-                delete_link(link.create_link_hash)?;
+    if !get_tool_links_for_developer_collective(original_developer_collective_hash.clone())?
+        .is_empty()
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "DeveloperCollective still has Tools. Use delete_developer_collective_cascade to delete or deprecate them.".to_string()
+        )));
+    }
+    delete_developer_collective_with_links(original_developer_collective_hash)
+}
+/// What happens to the Tools of a DeveloperCollective that is deleted with
+/// `delete_developer_collective_cascade`
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum ToolCascade {
+    /// The Tools are deleted
+    Delete,
+    /// The latest revision of every Tool is updated with the given deprecation notice
+    Deprecate { deprecation: String },
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeleteDeveloperCollectiveCascadeInput {
+    pub original_developer_collective_hash: ActionHash,
+    pub tools: ToolCascade,
+}
+/// Deletes a DeveloperCollective like `delete_developer_collective` after deleting or
+/// deprecating all of its Tools
+#[hdk_extern]
+pub fn delete_developer_collective_cascade(
+    input: DeleteDeveloperCollectiveCascadeInput,
+) -> ExternResult<ActionHash> {
+    let tool_links =
+        get_tool_links_for_developer_collective(input.original_developer_collective_hash.clone())?;
+    let original_tool_hashes: Vec<ActionHash> = tool_links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .collect();
+    match &input.tools {
+        ToolCascade::Delete => {
+            for original_tool_hash in original_tool_hashes {
+                delete_tool(original_tool_hash)?;
             }
         }
-    }
-    let owner_to_developer_collective_links = get_links(
-        GetLinksInputBuilder::try_new(
-            path.path_entry_hash()?,
-            LinkTypes::OwnerToDeveloperCollective,
-        )?
-        .build(),
-    )?;
-    for link in owner_to_developer_collective_links {
-        if let Some(hash) = link.target.into_action_hash() {
-            if hash.eq(&original_developer_collective_hash) {
-                delete_link(link.create_link_hash)?;
+        ToolCascade::Deprecate { deprecation } => {
+            let permission_hash = get_my_permission(
+                input.original_developer_collective_hash.clone(),
+            )?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Only owners of the DeveloperCollective can deprecate its Tools".to_string()
+            )))?;
+            for original_tool_hash in original_tool_hashes {
+                let Some(latest_tool_record) = get_latest_tool(original_tool_hash.clone())? else {
+                    continue;
+                };
+                let latest_tool: Tool = latest_tool_record
+                    .entry()
+                    .to_app_option()
+                    .map_err(|e| wasm_error!(e))?
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "Tool record has no entry".to_string()
+                    )))?;
+                update_tool(UpdateToolInput {
+                    original_tool_hash,
+                    previous_tool_hash: latest_tool_record.action_address().clone(),
+                    updated_tool: UpdatedTool {
+                        permission_hash: permission_hash.clone(),
+                        title: latest_tool.title,
+                        subtitle: latest_tool.subtitle,
+                        description: latest_tool.description,
+                        icon: migrate_icon(latest_tool.icon)?,
+                        version: latest_tool.version,
+                        source: latest_tool.source,
+                        hashes: latest_tool.hashes,
+                        changelog: latest_tool.changelog,
+                        meta_data: latest_tool.meta_data,
+                        deprecation: Some(deprecation.clone()),
+                        channel: latest_tool.channel,
+                        tags: latest_tool.tags,
+                    },
+                })?;
             }
         }
     }
-    delete_entry(original_developer_collective_hash)
+    delete_developer_collective_with_links(input.original_developer_collective_hash)
 }
 #[hdk_extern]
 pub fn get_all_deletes_for_developer_collective(
@@ -246,3 +307,124 @@ pub fn get_my_original_developer_collectives(_: ()) -> ExternResult<Vec<Record>>
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
     Ok(records.into_iter().flatten().collect())
 }
+/// Gets a page of the original records of the DeveloperCollectives owned by the calling agent
+#[hdk_extern]
+pub fn get_my_original_developer_collectives_page(page: PageInput) -> ExternResult<Page<Record>> {
+    let links = get_links(page.links_input(GetLinksInputBuilder::try_new(
+        agent_info()?.agent_initial_pubkey,
+        LinkTypes::OwnerToDeveloperCollective,
+    )?))?;
+    page.page_of_link_targets(links)
+}
+
+/// Gets every agent that may have an OwnerToDeveloperCollective link to the collective: its
+/// creator, the co-owners and the recipients of ownership transfers
+fn owner_candidates(
+    developer_collective_record: &Record,
+    developer_collective_hash: &ActionHash,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let mut agents = vec![developer_collective_record.action().author().clone()];
+    let grant_links =
+        get_co_ownerships_for_developer_collective(developer_collective_hash.clone())?
+            .into_iter()
+            .chain(get_ownership_transfers_for_developer_collective(
+                developer_collective_hash.clone(),
+            )?);
+    for link in grant_links {
+        if let Ok(agent) = AgentPubKey::from_raw_39(link.tag.0) {
+            if !agents.contains(&agent) {
+                agents.push(agent);
+            }
+        }
+    }
+    Ok(agents)
+}
+
+/// Deletes the links from the DeveloperCollective to its Curators and back
+fn delete_curator_links(developer_collective_hash: &ActionHash) -> ExternResult<()> {
+    for link in get_curators_for_developer_collective(developer_collective_hash.clone())? {
+        if let Some(curator_hash) = link.target.clone().into_action_hash() {
+            let curator_links = get_links(
+                GetLinksInputBuilder::try_new(
+                    curator_hash,
+                    LinkTypes::CuratorToDeveloperCollectives,
+                )?
+                .build(),
+            )?;
+            for curator_link in curator_links {
+                if curator_link.target.into_action_hash().as_ref()
+                    == Some(developer_collective_hash)
+                {
+                    delete_link(curator_link.create_link_hash)?;
+                }
+            }
+        }
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Deletes a DeveloperCollective together with every link pointing to it: the
+/// all_developer_collectives link, the owner links of all owners, the links from and to Curators
+/// and the links to its invitations and ContributorPermissions. Open invitations are withdrawn
+/// and ContributorPermissions are revoked.
+fn delete_developer_collective_with_links(
+    original_developer_collective_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+    let details = get_details(
+        original_developer_collective_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(
+        "DeveloperCollective not found".to_string()
+    )))?;
+    let record = match details {
+        Details::Record(details) => Ok(details.record),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(
+            "Malformed get details response".to_string()
+        ))),
+    }?;
+    delete_index_links(
+        "all_developer_collectives",
+        &original_developer_collective_hash.clone().into(),
+        LinkTypes::AllDeveloperCollectives,
+    )?;
+    delete_curator_links(&original_developer_collective_hash)?;
+    // Owner links are looked up from every agent that has ever been granted ownership, since
+    // removed owners are expected but not guaranteed to clean up their own link
+    let my_agent = agent_info()?.agent_initial_pubkey;
+    let mut owner_link_hashes = vec![];
+    let mut my_owner_link_hashes = vec![];
+    for agent in owner_candidates(&record, &original_developer_collective_hash)? {
+        let owner_to_developer_collective_links = get_links(
+            GetLinksInputBuilder::try_new(agent.clone(), LinkTypes::OwnerToDeveloperCollective)?
+                .build(),
+        )?;
+        for link in owner_to_developer_collective_links {
+            if link.target.into_action_hash().as_ref() == Some(&original_developer_collective_hash)
+            {
+                if agent == my_agent {
+                    my_owner_link_hashes.push(link.create_link_hash);
+                } else {
+                    owner_link_hashes.push(link.create_link_hash);
+                }
+            }
+        }
+    }
+    withdraw_invitations_for_developer_collective(original_developer_collective_hash.clone())?;
+    revoke_contributor_permissions_for_developer_collective(
+        original_developer_collective_hash.clone(),
+    )?;
+    let delete_hash = delete_entry(original_developer_collective_hash.clone())?;
+    // Permission links and the owner links of other agents may only be removed once the
+    // collective is gone
+    delete_contributor_permission_links(original_developer_collective_hash)?;
+    for owner_link_hash in owner_link_hashes {
+        delete_link(owner_link_hash)?;
+    }
+    // Our own owner link proves our ownership to validators, so it has to go last
+    for owner_link_hash in my_owner_link_hashes {
+        delete_link(owner_link_hash)?;
+    }
+    Ok(delete_hash)
+}
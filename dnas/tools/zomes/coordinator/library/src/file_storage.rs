@@ -0,0 +1,100 @@
+use hdk::prelude::*;
+use library_integrity::*;
+use serde_bytes::ByteBuf;
+
+use crate::tool_artifacts::compute_digest;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UploadArtifactInput {
+    pub name: String,         // File name, e.g. my-tool.webhapp
+    pub chunks: Vec<ByteBuf>, // The artifact bytes, possibly split into consecutive chunks
+}
+
+/// Stores a file, e.g. the .webhapp of a Tool release, on the DHT and returns the EntryHash
+/// of its FileManifest, which can be used as `DhtArtifact` source of a Tool. The file is
+/// split into chunks of FILE_CHUNK_SIZE bytes regardless of how the input is chunked.
+/// Files that are too large to be passed in a single call can be uploaded with
+/// `upload_artifact_chunk` and `create_artifact_manifest` instead.
+#[hdk_extern]
+pub fn upload_artifact(input: UploadArtifactInput) -> ExternResult<EntryHash> {
+    let bytes: Vec<u8> = input
+        .chunks
+        .into_iter()
+        .flat_map(|c| c.into_vec())
+        .collect();
+    let mut chunks = vec![];
+    for data in bytes.chunks(FILE_CHUNK_SIZE) {
+        chunks.push(store_chunk(data.to_vec())?);
+    }
+    let sha256 = compute_digest(&HashAlgorithm::Sha256, &bytes)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not compute the Sha256 digest of the file".to_string())
+    ))?;
+    store_manifest(FileManifest {
+        name: input.name,
+        size: bytes.len() as u64,
+        sha256,
+        chunks,
+    })
+}
+
+/// Stores one chunk of a file of at most FILE_CHUNK_SIZE bytes and returns its EntryHash, to be
+/// listed in `create_artifact_manifest` once all chunks of the file have been uploaded
+#[hdk_extern]
+pub fn upload_artifact_chunk(data: ByteBuf) -> ExternResult<EntryHash> {
+    store_chunk(data.into_vec())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateArtifactManifestInput {
+    pub name: String,   // File name, e.g. my-tool.webhapp
+    pub size: u64,      // Size of the whole file in bytes
+    pub sha256: String, // Lowercase hex Sha256 digest of the whole file
+    pub chunks: Vec<EntryHash>,
+}
+
+/// Creates the FileManifest of a file whose chunks have been uploaded with
+/// `upload_artifact_chunk` and returns its EntryHash
+#[hdk_extern]
+pub fn create_artifact_manifest(input: CreateArtifactManifestInput) -> ExternResult<EntryHash> {
+    store_manifest(FileManifest {
+        name: input.name,
+        size: input.size,
+        sha256: input.sha256,
+        chunks: input.chunks,
+    })
+}
+
+/// Identical chunks are only stored once
+fn store_chunk(data: Vec<u8>) -> ExternResult<EntryHash> {
+    let chunk = FileChunk { data };
+    let chunk_hash = hash_entry(&chunk)?;
+    if get(chunk_hash.clone(), GetOptions::default())?.is_none() {
+        create_entry(&EntryTypes::FileChunk(chunk))?;
+    }
+    Ok(chunk_hash)
+}
+
+/// Identical manifests are only stored once
+fn store_manifest(manifest: FileManifest) -> ExternResult<EntryHash> {
+    let manifest_hash = hash_entry(&manifest)?;
+    if get(manifest_hash.clone(), GetOptions::default())?.is_none() {
+        create_entry(&EntryTypes::FileManifest(manifest))?;
+    }
+    Ok(manifest_hash)
+}
+#[hdk_extern]
+pub fn get_artifact_manifest(manifest_hash: EntryHash) -> ExternResult<Option<FileManifest>> {
+    let Some(record) = get(manifest_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    record.entry().to_app_option().map_err(|e| wasm_error!(e))
+}
+/// Gets one of the chunks listed in a FileManifest. Chunks are fetched one by one so that
+/// large files don't need to be transferred in a single call.
+#[hdk_extern]
+pub fn get_artifact_chunk(chunk_hash: EntryHash) -> ExternResult<Option<FileChunk>> {
+    let Some(record) = get(chunk_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    record.entry().to_app_option().map_err(|e| wasm_error!(e))
+}
@@ -0,0 +1,52 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hdk::prelude::*;
+use library_integrity::*;
+
+/// Stores an Icon and returns its EntryHash. If an identical image has been stored before,
+/// no new entry is created and the EntryHash of the existing one is returned.
+#[hdk_extern]
+pub fn create_icon(icon: Icon) -> ExternResult<EntryHash> {
+    let icon_hash = hash_entry(&icon)?;
+    if get(icon_hash.clone(), GetOptions::default())?.is_none() {
+        create_entry(&EntryTypes::Icon(icon))?;
+    }
+    Ok(icon_hash)
+}
+#[hdk_extern]
+pub fn get_icon(icon_hash: EntryHash) -> ExternResult<Option<Icon>> {
+    let Some(record) = get(icon_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    record.entry().to_app_option().map_err(|e| wasm_error!(e))
+}
+
+/// Parses the base64 encoded data URL that legacy Tool entries have as their icon
+fn icon_from_data_url(data_url: &str) -> Option<Icon> {
+    let (mime_type, data) = data_url
+        .strip_prefix("data:")
+        .and_then(|icon| icon.split_once(";base64,"))?;
+    Some(Icon {
+        mime_type: mime_type.to_string(),
+        data: STANDARD.decode(data).ok()?,
+    })
+}
+
+/// Gets the image an IconRef refers to, whether it is stored as an Icon entry or inline
+pub(crate) fn resolve_icon(icon: &IconRef) -> ExternResult<Option<Icon>> {
+    match icon {
+        IconRef::Hash(icon_hash) => get_icon(icon_hash.clone()),
+        IconRef::Legacy(data_url) => Ok(icon_from_data_url(data_url)),
+    }
+}
+
+/// Stores the inline image of a legacy entry as an Icon entry, so that a new revision of the
+/// entry can refer to it
+pub(crate) fn migrate_icon(icon: IconRef) -> ExternResult<IconRef> {
+    let IconRef::Legacy(data_url) = icon else {
+        return Ok(icon);
+    };
+    let icon = icon_from_data_url(&data_url).ok_or(wasm_error!(WasmErrorInner::Guest(
+        "The inline icon is not a base64 encoded data URL".to_string()
+    )))?;
+    Ok(IconRef::Hash(create_icon(icon)?))
+}
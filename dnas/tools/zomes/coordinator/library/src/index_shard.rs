@@ -0,0 +1,65 @@
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::pagination::PageInput;
+
+/// Links the target from the shard of the global index anchor that belongs to it
+pub(crate) fn create_index_link(
+    anchor: &str,
+    target: AnyLinkableHash,
+    link_type: LinkTypes,
+) -> ExternResult<ActionHash> {
+    let path = index_shard_path(anchor, &target);
+    create_link(path.path_entry_hash()?, target, link_type, ())
+}
+
+/// Gets the links of a global index anchor from all of its shards in a single batch
+pub(crate) fn get_index_links(anchor: &str, link_type: LinkTypes) -> ExternResult<Vec<Link>> {
+    get_index_links_with(anchor, link_type, GetLinksInputBuilder::build)
+}
+
+/// Gets the links of a global index anchor that can belong to the page or any page after it
+pub(crate) fn get_index_links_for_page(
+    anchor: &str,
+    link_type: LinkTypes,
+    page: &PageInput,
+) -> ExternResult<Vec<Link>> {
+    get_index_links_with(anchor, link_type, |builder| page.links_input(builder))
+}
+
+fn get_index_links_with(
+    anchor: &str,
+    link_type: LinkTypes,
+    build: impl Fn(GetLinksInputBuilder) -> GetLinksInput,
+) -> ExternResult<Vec<Link>> {
+    let get_links_input = index_paths(anchor)
+        .into_iter()
+        .map(|path| {
+            Ok(build(GetLinksInputBuilder::try_new(
+                path.path_entry_hash()?,
+                link_type,
+            )?))
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links = HDK.with(|hdk| hdk.borrow().get_links(get_links_input))?;
+    Ok(links.into_iter().flatten().collect())
+}
+
+/// Deletes the links from a global index anchor to the target, both from its shard and from the
+/// unsharded anchor
+pub(crate) fn delete_index_links(
+    anchor: &str,
+    target: &AnyLinkableHash,
+    link_type: LinkTypes,
+) -> ExternResult<()> {
+    for path in [index_shard_path(anchor, target), Path::from(anchor)] {
+        let links =
+            get_links(GetLinksInputBuilder::try_new(path.path_entry_hash()?, link_type)?.build())?;
+        for link in links {
+            if &link.target == target {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    Ok(())
+}
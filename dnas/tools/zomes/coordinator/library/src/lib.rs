@@ -1,27 +1,64 @@
 pub mod all_agents;
 pub mod all_curators;
 pub mod all_developer_collectives;
+pub mod all_tools;
+pub mod co_ownership;
+pub mod contributor_invitation;
 pub mod contributor_permission;
 pub mod curator;
 pub mod curator_to_developer_collectives;
 pub mod curator_to_tools;
 pub mod developer_collective;
+pub mod file_storage;
+pub mod icon;
+pub mod index_shard;
+pub mod ownership_transfer;
+pub mod pagination;
+pub mod search;
 pub mod tool;
+pub mod tool_artifacts;
+pub mod tool_summary;
+pub mod tool_tags;
 use hdk::prelude::*;
 use library_integrity::*;
+
+use crate::index_shard::create_index_link;
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
-    let path = Path::from("all_agents");
-    create_link(
-        path.path_entry_hash()?,
-        agent_info()?.agent_initial_pubkey,
+    create_index_link(
+        "all_agents",
+        agent_info()?.agent_initial_pubkey.into(),
         LinkTypes::AllAgents,
-        (),
     )?;
+    // Allow other agents to notify us, e.g. about invitations to contribute or revocations of
+    // our permissions
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "recv_remote_signal".into()));
+    functions.insert((zome_info()?.name, "recv_permission_revocation".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: "remote_notifications".into(),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
     Ok(InitCallbackResult::Pass)
 }
+/// Forwards notifications sent by other agents to the UI
+#[hdk_extern]
+pub fn recv_remote_signal(signal: RemoteSignal) -> ExternResult<()> {
+    emit_signal(signal)
+}
+/// What other agents can notify us about. Any agent can send these, so they only carry the
+/// hashes of records that the UI fetches and checks itself.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum RemoteSignal {
+    InvitationReceived {
+        contributor_invitation_hash: ActionHash,
+    },
+}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum Signal {
     LinkCreated {
         action: SignedActionHashed,
@@ -0,0 +1,209 @@
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::co_ownership::get_co_ownerships_for_developer_collective;
+use crate::contributor_permission::get_permission_revocations;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OfferOwnershipTransferInput {
+    pub developer_collective_hash: ActionHash,
+    pub to_agent: AgentPubKey,
+}
+/// Offers to hand a DeveloperCollective over to another agent. The calling agent is no longer
+/// an owner of the collective from here on, unless the recipient declines the offer and the
+/// calling agent reclaims the collective.
+#[hdk_extern]
+pub fn offer_ownership_transfer(input: OfferOwnershipTransferInput) -> ExternResult<Record> {
+    let ownership_transfer_hash =
+        create_entry(&EntryTypes::OwnershipTransfer(OwnershipTransfer {
+            for_collective: input.developer_collective_hash.clone(),
+            to_agent: input.to_agent.clone(),
+        }))?;
+    create_link(
+        input.developer_collective_hash.clone(),
+        ownership_transfer_hash.clone(),
+        LinkTypes::DeveloperCollectiveToOwnershipTransfers,
+        // We also add a tag to the link for which agent the transfer is
+        LinkTag::new(input.to_agent.get_raw_39()),
+    )?;
+    create_link(
+        input.to_agent,
+        ownership_transfer_hash.clone(),
+        LinkTypes::RecipientToOwnershipTransfers,
+        // We also add a tag to the link for which collective the transfer is
+        LinkTag::new(input.developer_collective_hash.get_raw_39()),
+    )?;
+    let record = get(ownership_transfer_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created OwnershipTransfer".to_string())
+    ))?;
+    Ok(record)
+}
+/// Accepts an ownership transfer by linking the DeveloperCollective from the agent's own
+/// public key
+#[hdk_extern]
+pub fn accept_ownership_transfer(ownership_transfer_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record =
+        get(ownership_transfer_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("OwnershipTransfer not found".to_string())
+        ))?;
+    let ownership_transfer = ownership_transfer_from_record(&record)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Record does not contain an OwnershipTransfer entry".to_string())
+    ))?;
+    create_link(
+        ownership_transfer.to_agent,
+        ownership_transfer.for_collective,
+        LinkTypes::OwnerToDeveloperCollective,
+        // Tag must contain the OwnershipTransfer action hash here:
+        LinkTag::new(ownership_transfer_hash.get_raw_39()),
+    )
+}
+/// Declines an ownership transfer that has been offered to the calling agent by deleting it.
+/// The offering agent can then reclaim the collective with `reclaim_developer_collective`.
+#[hdk_extern]
+pub fn decline_ownership_transfer(ownership_transfer_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record =
+        get(ownership_transfer_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("OwnershipTransfer not found".to_string())
+        ))?;
+    let ownership_transfer = ownership_transfer_from_record(&record)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Record does not contain an OwnershipTransfer entry".to_string())
+    ))?;
+    if is_ownership_transfer_accepted(
+        &ownership_transfer.to_agent,
+        &ownership_transfer.for_collective,
+        &ownership_transfer_hash,
+    )? {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The ownership transfer has already been accepted".to_string()
+        )));
+    }
+    for link in get_ownership_transfers_for_recipient(ownership_transfer.to_agent)? {
+        if link.target.into_action_hash().as_ref() == Some(&ownership_transfer_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(ownership_transfer_hash)
+}
+/// Makes the calling agent owner of a DeveloperCollective again after the recipient declined
+/// the ownership transfer they offered, by linking the collective from the agent's own public
+/// key with the action hash of the decline in the tag
+#[hdk_extern]
+pub fn reclaim_developer_collective(
+    ownership_transfer_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+    let Some(Details::Record(details)) =
+        get_details(ownership_transfer_hash.clone(), GetOptions::default())?
+    else {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "OwnershipTransfer not found".to_string()
+        )));
+    };
+    let ownership_transfer =
+        ownership_transfer_from_record(&details.record)?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("Record does not contain an OwnershipTransfer entry".to_string())
+        ))?;
+    let decline = details
+        .deletes
+        .into_iter()
+        .find(|delete| delete.action().author() == &ownership_transfer.to_agent)
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "The ownership transfer has not been declined".to_string()
+        )))?;
+    let owner_link_hash = create_link(
+        agent_info()?.agent_initial_pubkey,
+        ownership_transfer.for_collective.clone(),
+        LinkTypes::OwnerToDeveloperCollective,
+        // Tag must contain the action hash of the decline here:
+        LinkTag::new(decline.action_address().get_raw_39()),
+    )?;
+    for link in get_ownership_transfers_for_developer_collective(ownership_transfer.for_collective)?
+    {
+        if link.target.into_action_hash().as_ref() == Some(&ownership_transfer_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(owner_link_hash)
+}
+#[hdk_extern]
+pub fn get_ownership_transfers_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(
+            developer_collective_hash,
+            LinkTypes::DeveloperCollectiveToOwnershipTransfers,
+        )?
+        .build(),
+    )
+}
+#[hdk_extern]
+pub fn get_ownership_transfers_for_recipient(recipient: AgentPubKey) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(recipient, LinkTypes::RecipientToOwnershipTransfers)?.build(),
+    )
+}
+
+/// Gets the current owners of a DeveloperCollective together with the action hash that each of
+/// them uses as permission hash, i.e. the Create action of the collective for its creator, or
+/// their CoOwnership or accepted OwnershipTransfer
+pub(crate) fn get_owner_permissions(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<Vec<(AgentPubKey, ActionHash)>> {
+    let Some(record) = get(developer_collective_hash.clone(), GetOptions::default())? else {
+        return Ok(vec![]);
+    };
+    let mut owners = vec![(
+        record.action().author().clone(),
+        developer_collective_hash.clone(),
+    )];
+    for link in get_co_ownerships_for_developer_collective(developer_collective_hash.clone())? {
+        if let (Ok(agent), Some(co_ownership_hash)) = (
+            AgentPubKey::from_raw_39(link.tag.0),
+            link.target.into_action_hash(),
+        ) {
+            if !owners.iter().any(|(owner, _)| owner == &agent)
+                && get_permission_revocations(co_ownership_hash.clone())?.is_empty()
+            {
+                owners.push((agent, co_ownership_hash));
+            }
+        }
+    }
+    // Apply transfers in the order they have been offered. Offering a transfer ends the
+    // ownership of the offering agent, the recipient only becomes owner once they accept.
+    let mut transfer_links =
+        get_ownership_transfers_for_developer_collective(developer_collective_hash.clone())?;
+    transfer_links.sort_by_key(|link| link.timestamp);
+    for link in transfer_links {
+        owners.retain(|(owner, _)| owner != &link.author);
+        let (Ok(recipient), Some(ownership_transfer_hash)) = (
+            AgentPubKey::from_raw_39(link.tag.0),
+            link.target.into_action_hash(),
+        ) else {
+            continue;
+        };
+        if is_ownership_transfer_accepted(
+            &recipient,
+            &developer_collective_hash,
+            &ownership_transfer_hash,
+        )? {
+            owners.retain(|(owner, _)| owner != &recipient);
+            owners.push((recipient, ownership_transfer_hash));
+        }
+    }
+    Ok(owners)
+}
+
+fn is_ownership_transfer_accepted(
+    recipient: &AgentPubKey,
+    developer_collective_hash: &ActionHash,
+    ownership_transfer_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let owner_links = get_links(
+        GetLinksInputBuilder::try_new(recipient.clone(), LinkTypes::OwnerToDeveloperCollective)?
+            .build(),
+    )?;
+    Ok(owner_links.into_iter().any(|link| {
+        link.target.into_action_hash().as_ref() == Some(developer_collective_hash)
+            && link.tag.0 == ownership_transfer_hash.get_raw_39()
+    }))
+}
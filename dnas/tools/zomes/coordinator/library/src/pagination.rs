@@ -0,0 +1,103 @@
+use hdk::prelude::*;
+
+/// Maximum number of items that a single page can hold
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Position of the last item of a page in a list of links, which are ordered by their
+/// timestamp and then by the hash of their CreateLink action
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PageCursor {
+    pub timestamp: Timestamp,
+    pub create_link_hash: ActionHash,
+}
+
+impl PageCursor {
+    fn of(link: &Link) -> Self {
+        PageCursor {
+            timestamp: link.timestamp,
+            create_link_hash: link.create_link_hash.clone(),
+        }
+    }
+
+    fn is_before(&self, link: &Link) -> bool {
+        (&self.timestamp, &self.create_link_hash) < (&link.timestamp, &link.create_link_hash)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PageInput {
+    /// Number of items to return, capped at MAX_PAGE_LIMIT
+    pub limit: u32,
+    /// The `next` cursor of the previous page, or None to get the first page
+    pub after: Option<PageCursor>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor to pass as `after` to get the next page, None if this is the last page
+    pub next: Option<PageCursor>,
+}
+
+impl PageInput {
+    /// Restricts the query to links created at or after the cursor, so that authorities don't
+    /// have to return links of previous pages
+    pub(crate) fn links_input(&self, builder: GetLinksInputBuilder) -> GetLinksInput {
+        match &self.after {
+            Some(cursor) => builder
+                .after(Timestamp::from_micros(
+                    cursor.timestamp.as_micros().saturating_sub(1),
+                ))
+                .build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Gets the links of this page and the cursor of the next one
+    pub(crate) fn page_of_links(&self, mut links: Vec<Link>) -> Page<Link> {
+        links.sort_by(|link_a, link_b| {
+            (link_a.timestamp, &link_a.create_link_hash)
+                .cmp(&(link_b.timestamp, &link_b.create_link_hash))
+        });
+        let mut links: Vec<Link> = links
+            .into_iter()
+            .filter(|link| match &self.after {
+                Some(cursor) => cursor.is_before(link),
+                None => true,
+            })
+            .collect();
+        let limit = self.limit.clamp(1, MAX_PAGE_LIMIT) as usize;
+        let next = if links.len() > limit {
+            links.truncate(limit);
+            links.last().map(PageCursor::of)
+        } else {
+            None
+        };
+        Page { items: links, next }
+    }
+
+    /// Gets the records that the links of this page point to in a single batch
+    pub(crate) fn page_of_link_targets(&self, links: Vec<Link>) -> ExternResult<Page<Record>> {
+        let page = self.page_of_links(links);
+        let get_input: Vec<GetInput> = page
+            .items
+            .into_iter()
+            .map(|link| {
+                Ok(GetInput::new(
+                    link.target
+                        .into_action_hash()
+                        .ok_or(wasm_error!(WasmErrorInner::Guest(
+                            "No action hash associated with link".to_string()
+                        )))?
+                        .into(),
+                    GetOptions::default(),
+                ))
+            })
+            .collect::<ExternResult<Vec<GetInput>>>()?;
+        let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+        Ok(Page {
+            items: records.into_iter().flatten().collect(),
+            next: page.next,
+        })
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::BTreeSet;
+
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::ownership_transfer::get_owner_permissions;
+use crate::tool::get_latest_tools;
+
+/// Updates the links of a Tool from the paths of the search terms it can be found by when a
+/// revision changes them. Terms that the previous revision could already be found by are still
+/// linked to it and are skipped, the links from terms that have been removed are deleted.
+/// Pass no `tool` when the Tool is deleted to remove it from all search terms of `previous_tool`.
+pub(crate) fn update_search_term_links(
+    original_tool_hash: &ActionHash,
+    tool_hash: &ActionHash,
+    tool: Option<&Tool>,
+    previous_tool: Option<&Tool>,
+) -> ExternResult<()> {
+    let terms = tool.map(|tool| tool.search_terms()).unwrap_or_default();
+    let previous_terms = previous_tool
+        .map(|previous_tool| previous_tool.search_terms())
+        .unwrap_or_default();
+    for term in terms.difference(&previous_terms) {
+        create_link(
+            search_term_path(term).path_entry_hash()?,
+            tool_hash.clone(),
+            LinkTypes::SearchTermToTools,
+            search_term_link_tag(original_tool_hash, term),
+        )?;
+    }
+    let removed_terms: Vec<&String> = previous_terms.difference(&terms).collect();
+    if removed_terms.is_empty() {
+        return Ok(());
+    }
+    // Links created by other contributors can only be deleted by owners, search_tools skips
+    // Tools whose latest revision doesn't match the query anymore either way
+    let my_agent = agent_info()?.agent_initial_pubkey;
+    let is_owner = match tool.or(previous_tool) {
+        Some(tool) => get_owner_permissions(tool.developer_collective.clone())?
+            .iter()
+            .any(|(owner, _)| owner == &my_agent),
+        None => false,
+    };
+    let get_links_input = removed_terms
+        .iter()
+        .map(|term| {
+            Ok(GetLinksInputBuilder::try_new(
+                search_term_path(term).path_entry_hash()?,
+                LinkTypes::SearchTermToTools,
+            )?
+            .tag_prefix(search_term_link_tag(original_tool_hash, term))
+            .build())
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links_per_term = HDK.with(|hdk| hdk.borrow().get_links(get_links_input))?;
+    for link in links_per_term.into_iter().flatten() {
+        if link.author == my_agent || is_owner {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Counts the words of the query that are the beginning of one of the words
+fn count_matching_words(query_words: &[String], words: &BTreeSet<String>) -> usize {
+    query_words
+        .iter()
+        .filter(|query_word| {
+            words
+                .iter()
+                .any(|word| word.starts_with(query_word.as_str()))
+        })
+        .count()
+}
+
+/// Searches Tools whose title, subtitle or tags contain words starting with every word of the query.
+/// Returns the latest revisions of the matching Tools, the ones that match the query in their
+/// title first and the most recently updated ones first after that.
+#[hdk_extern]
+pub fn search_tools(query: String) -> ExternResult<Vec<Record>> {
+    // Words that are too short to be indexed are ignored
+    let (query_words, terms): (Vec<String>, BTreeSet<String>) = normalize_search_words(&query)
+        .into_iter()
+        .filter_map(|word| search_term_for_word(&word).map(|term| (word, term)))
+        .unzip();
+    if terms.is_empty() {
+        return Ok(vec![]);
+    }
+    let get_links_input = terms
+        .iter()
+        .map(|term| {
+            Ok(GetLinksInputBuilder::try_new(
+                search_term_path(term).path_entry_hash()?,
+                LinkTypes::SearchTermToTools,
+            )?
+            .build())
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links_per_term = HDK.with(|hdk| hdk.borrow().get_links(get_links_input))?;
+    // Only Tools that are linked from every term can match the query
+    let mut original_tool_hashes: Option<BTreeSet<ActionHash>> = None;
+    for links in links_per_term {
+        let hashes: BTreeSet<ActionHash> = links
+            .into_iter()
+            .filter_map(|link| parse_search_term_link_tag(&link.tag).ok())
+            .map(|(original_tool_hash, _term)| original_tool_hash)
+            .collect();
+        original_tool_hashes = Some(match original_tool_hashes {
+            Some(previous) => previous.intersection(&hashes).cloned().collect(),
+            None => hashes,
+        });
+    }
+    let original_tool_hashes: Vec<ActionHash> = original_tool_hashes
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+
+    let get_details_input: Vec<GetInput> = original_tool_hashes
+        .iter()
+        .map(|hash| GetInput::new(hash.clone().into(), GetOptions::default()))
+        .collect();
+    let details = HDK.with(|hdk| hdk.borrow().get_details(get_details_input))?;
+    // Deleted Tools are not found anymore
+    let original_tool_hashes: Vec<ActionHash> = original_tool_hashes
+        .into_iter()
+        .zip(details)
+        .filter_map(|(original_tool_hash, details)| match details {
+            Some(Details::Record(details)) if details.deletes.is_empty() => {
+                Some(original_tool_hash)
+            }
+            _ => None,
+        })
+        .collect();
+    let mut results: Vec<(usize, Timestamp, Record)> = vec![];
+    for record in get_latest_tools(&original_tool_hashes)?
+        .into_iter()
+        .flatten()
+    {
+        let Some(tool) = record
+            .entry()
+            .to_app_option::<Tool>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        // Links of previous revisions may no longer match the latest one, and terms only
+        // contain the beginning of the words of the query
+        if count_matching_words(&query_words, &tool.search_words()) < query_words.len() {
+            continue;
+        }
+        let title_words: BTreeSet<String> =
+            normalize_search_words(&tool.title).into_iter().collect();
+        let title_matches = count_matching_words(&query_words, &title_words);
+        results.push((title_matches, record.action().timestamp(), record));
+    }
+    results.sort_by(|(matches_a, timestamp_a, _), (matches_b, timestamp_b, _)| {
+        matches_b
+            .cmp(matches_a)
+            .then_with(|| timestamp_b.cmp(timestamp_a))
+    });
+    Ok(results.into_iter().map(|(_, _, record)| record).collect())
+}
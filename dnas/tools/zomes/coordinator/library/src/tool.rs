@@ -1,7 +1,13 @@
+use crate::contributor_permission::ensure_permission_not_revoked;
+use crate::index_shard::{create_index_link, delete_index_links};
+use crate::pagination::{Page, PageInput};
+use crate::search::update_search_term_links;
+use crate::tool_tags::update_tag_links;
 use hdk::prelude::*;
 use library_integrity::*;
 #[hdk_extern]
 pub fn create_tool(tool: Tool) -> ExternResult<Record> {
+    ensure_permission_not_revoked(tool.permission_hash.clone())?;
     let tool_hash = create_entry(&EntryTypes::Tool(tool.clone()))?;
     create_link(
         tool.developer_collective.clone(),
@@ -10,6 +16,9 @@ pub fn create_tool(tool: Tool) -> ExternResult<Record> {
         // Tag must contain the permission action hash here:
         LinkTag::new(tool.permission_hash.get_raw_39()),
     )?;
+    create_index_link("all_tools", tool_hash.clone().into(), LinkTypes::AllTools)?;
+    update_search_term_links(&tool_hash, &tool_hash, Some(&tool), None)?;
+    update_tag_links(&tool_hash, &tool_hash, Some(&tool), None)?;
     let record = get(tool_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly created Tool".to_string())
     ))?;
@@ -20,21 +29,127 @@ pub fn get_latest_tool(original_tool_hash: ActionHash) -> ExternResult<Option<Re
     let links = get_links(
         GetLinksInputBuilder::try_new(original_tool_hash.clone(), LinkTypes::ToolUpdates)?.build(),
     )?;
+    let latest_tool_hash = latest_tool_hash(original_tool_hash, links)?;
+    get(latest_tool_hash, GetOptions::default())
+}
+/// Gets the latest revisions of several Tools with one batch of get_links and one batch of gets
+pub(crate) fn get_latest_tools(
+    original_tool_hashes: &[ActionHash],
+) -> ExternResult<Vec<Option<Record>>> {
+    let get_links_input = original_tool_hashes
+        .iter()
+        .map(|hash| {
+            Ok(GetLinksInputBuilder::try_new(hash.clone(), LinkTypes::ToolUpdates)?.build())
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links_per_tool = HDK.with(|hdk| hdk.borrow().get_links(get_links_input))?;
+    let get_input = original_tool_hashes
+        .iter()
+        .zip(links_per_tool)
+        .map(|(hash, links)| {
+            Ok(GetInput::new(
+                latest_tool_hash(hash.clone(), links)?.into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    HDK.with(|hdk| hdk.borrow().get(get_input))
+}
+/// The target of the most recent ToolUpdates link, or the original Tool if it has no updates
+fn latest_tool_hash(original_tool_hash: ActionHash, links: Vec<Link>) -> ExternResult<ActionHash> {
     let latest_link = links
         .into_iter()
         .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_tool_hash = match latest_link {
-        Some(link) => {
-            link.target
-                .clone()
-                .into_action_hash()
-                .ok_or(wasm_error!(WasmErrorInner::Guest(
-                    "No action hash associated with link".to_string()
-                )))?
-        }
-        None => original_tool_hash.clone(),
+    match latest_link {
+        Some(link) => link
+            .target
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            ))),
+        None => Ok(original_tool_hash),
+    }
+}
+/// Gets the revision of a Tool with the highest semantic version. Revisions with the same
+/// version are ordered by their timestamp and revisions without a valid semantic version
+/// are considered older than any revision that has one.
+#[hdk_extern]
+pub fn get_latest_tool_by_version(original_tool_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let revisions = get_all_revisions_for_tool(original_tool_hash)?;
+    Ok(latest_revision_by_version(revisions))
+}
+
+fn latest_revision_by_version(revisions: Vec<Record>) -> Option<Record> {
+    revisions
+        .into_iter()
+        .filter_map(|record| {
+            let tool: Tool = record.entry().to_app_option().ok()??;
+            Some((tool.semver(), record))
+        })
+        .max_by(|(version_a, record_a), (version_b, record_b)| {
+            let by_version = match (version_a, version_b) {
+                (Some(a), Some(b)) => a.cmp_precedence(b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            };
+            by_version.then(
+                record_a
+                    .action()
+                    .timestamp()
+                    .cmp(&record_b.action().timestamp()),
+            )
+        })
+        .map(|(_, record)| record)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetLatestToolForChannelInput {
+    pub original_tool_hash: ActionHash,
+    pub channel: ReleaseChannel,
+}
+
+/// Gets the revision of a Tool with the highest semantic version among the revisions
+/// published to the given channel or to a more stable one, i.e. agents following the beta
+/// channel also get stable releases if they are newer than the latest beta release.
+#[hdk_extern]
+pub fn get_latest_tool_for_channel(
+    input: GetLatestToolForChannelInput,
+) -> ExternResult<Option<Record>> {
+    let Some(original_record) = get_original_tool(input.original_tool_hash.clone())? else {
+        return Ok(None);
     };
-    get(latest_tool_hash, GetOptions::default())
+    let links = get_links(
+        GetLinksInputBuilder::try_new(input.original_tool_hash, LinkTypes::ToolUpdates)?.build(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter(|link| match parse_tool_updates_link_tag(&link.tag) {
+            Ok((_, channel)) => input.channel.includes(&channel),
+            Err(_) => false,
+        })
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut records: Vec<Record> = records.into_iter().flatten().collect();
+    let original_tool: Option<Tool> = original_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?;
+    if let Some(original_tool) = original_tool {
+        if input.channel.includes(&original_tool.channel) {
+            records.push(original_record);
+        }
+    }
+    Ok(latest_revision_by_version(records))
 }
 #[hdk_extern]
 pub fn get_original_tool(original_tool_hash: ActionHash) -> ExternResult<Option<Record>> {
@@ -75,6 +190,26 @@ pub fn get_all_revisions_for_tool(original_tool_hash: ActionHash) -> ExternResul
     records.insert(0, original_record);
     Ok(records)
 }
+/// Gets the structured artifact hashes declared by the given revision of a Tool
+#[hdk_extern]
+pub fn get_tool_artifact_hashes(tool_hash: ActionHash) -> ExternResult<Vec<ArtifactHash>> {
+    let record = get(tool_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Tool not found".to_string())
+    ))?;
+    let tool: Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Record does not contain a Tool entry".to_string()
+        )))?;
+    match tool.hashes {
+        ToolHashes::V1(hashes) => Ok(hashes),
+        ToolHashes::Legacy(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "Tool has unstructured legacy hashes that cannot be parsed".to_string()
+        ))),
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct UpdatedTool {
@@ -83,13 +218,17 @@ pub struct UpdatedTool {
     pub title: String,
     pub subtitle: String,
     pub description: String,
-    pub icon: String, // base64 string
+    pub icon: IconRef,
     pub version: String,
-    pub source: String, // JSON string containing information about where to get this Tool from
-    pub hashes: String, // Hashes related to this Tool to verify its integrity
+    pub source: ToolSource,
+    pub hashes: ToolHashes,
     pub changelog: Option<String>,
     pub meta_data: Option<String>,
     pub deprecation: Option<String>,
+    #[serde(default)]
+    pub channel: ReleaseChannel,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -100,6 +239,7 @@ pub struct UpdateToolInput {
 }
 #[hdk_extern]
 pub fn update_tool(input: UpdateToolInput) -> ExternResult<Record> {
+    ensure_permission_not_revoked(input.updated_tool.permission_hash.clone())?;
     let original_tool_record = get(input.original_tool_hash.clone(), GetOptions::default())?
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Failed to get original Tool record".into()
@@ -124,14 +264,36 @@ pub fn update_tool(input: UpdateToolInput) -> ExternResult<Record> {
         changelog: input.updated_tool.changelog,
         meta_data: input.updated_tool.meta_data,
         deprecation: input.updated_tool.deprecation,
+        channel: input.updated_tool.channel,
+        tags: input.updated_tool.tags,
     };
-    let updated_tool_hash = update_entry(input.previous_tool_hash.clone(), updated_tool)?;
+    let previous_tool: Option<Tool> = get(input.previous_tool_hash.clone(), GetOptions::default())?
+        .map(|record| record.entry().to_app_option())
+        .transpose()
+        .map_err(|e| wasm_error!(e))?
+        .flatten();
+    let updated_tool_hash = update_entry(input.previous_tool_hash.clone(), updated_tool.clone())?;
     create_link(
         input.original_tool_hash.clone(),
         updated_tool_hash.clone(),
         LinkTypes::ToolUpdates,
-        // Tag must contain the permission action hash here:
-        LinkTag::new(input.updated_tool.permission_hash.get_raw_39()),
+        // Tag must contain the permission action hash and the release channel here:
+        tool_updates_link_tag(
+            &input.updated_tool.permission_hash,
+            &input.updated_tool.channel,
+        ),
+    )?;
+    update_search_term_links(
+        &input.original_tool_hash,
+        &updated_tool_hash,
+        Some(&updated_tool),
+        previous_tool.as_ref(),
+    )?;
+    update_tag_links(
+        &input.original_tool_hash,
+        &updated_tool_hash,
+        Some(&updated_tool),
+        previous_tool.as_ref(),
     )?;
     let record = get(updated_tool_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly updated Tool".to_string())
@@ -171,6 +333,28 @@ pub fn delete_tool(original_tool_hash: ActionHash) -> ExternResult<ActionHash> {
             }
         }
     }
+    delete_index_links(
+        "all_tools",
+        &original_tool_hash.clone().into(),
+        LinkTypes::AllTools,
+    )?;
+    let latest_tool: Option<Tool> = get_latest_tool(original_tool_hash.clone())?
+        .map(|record| record.entry().to_app_option())
+        .transpose()
+        .map_err(|e| wasm_error!(e))?
+        .flatten();
+    update_search_term_links(
+        &original_tool_hash,
+        &original_tool_hash,
+        None,
+        latest_tool.as_ref(),
+    )?;
+    update_tag_links(
+        &original_tool_hash,
+        &original_tool_hash,
+        None,
+        latest_tool.as_ref(),
+    )?;
     delete_entry(original_tool_hash)
 }
 #[hdk_extern]
@@ -202,6 +386,56 @@ pub fn get_oldest_delete_for_tool(
     });
     Ok(deletes.first().cloned())
 }
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddToolForDeveloperCollectiveInput {
+    pub base_developer_collective_hash: ActionHash,
+    pub target_tool_hash: ActionHash,
+    pub permission_hash: ActionHash, // Either the CreateAction hash of the DeveloperCollective entry or an ActionHash of a ContributorPermission entry
+}
+/// Links an existing Tool of a DeveloperCollective to it, e.g. to restore a link that has been
+/// removed by accident
+#[hdk_extern]
+pub fn add_tool_for_developer_collective(
+    input: AddToolForDeveloperCollectiveInput,
+) -> ExternResult<ActionHash> {
+    ensure_permission_not_revoked(input.permission_hash.clone())?;
+    create_link(
+        input.base_developer_collective_hash,
+        input.target_tool_hash,
+        LinkTypes::DeveloperCollectiveToTools,
+        // Tag must contain the permission action hash here:
+        LinkTag::new(input.permission_hash.get_raw_39()),
+    )
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddToolUpdateInput {
+    pub original_tool_hash: ActionHash,
+    pub updated_tool_hash: ActionHash,
+    pub permission_hash: ActionHash, // Either the CreateAction hash of the DeveloperCollective entry or an ActionHash of a ContributorPermission entry
+}
+/// Links an existing update of a Tool from the original Tool, e.g. to restore a link that has
+/// been removed by accident. The link carries the release channel of the update.
+#[hdk_extern]
+pub fn add_tool_update(input: AddToolUpdateInput) -> ExternResult<ActionHash> {
+    ensure_permission_not_revoked(input.permission_hash.clone())?;
+    let updated_tool: Tool = get(input.updated_tool_hash.clone(), GetOptions::default())?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Tool update not found".to_string()
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Tool record has no entry".to_string()
+        )))?;
+    create_link(
+        input.original_tool_hash,
+        input.updated_tool_hash,
+        LinkTypes::ToolUpdates,
+        // Tag must contain the permission action hash and the release channel here:
+        tool_updates_link_tag(&input.permission_hash, &updated_tool.channel),
+    )
+}
 #[hdk_extern]
 pub fn get_tool_links_for_developer_collective(
     developer_collective_hash: ActionHash,
@@ -242,6 +476,22 @@ pub fn get_original_tools_for_developer_collective(
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
     Ok(records.into_iter().flatten().collect())
 }
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetOriginalToolsForDeveloperCollectivePageInput {
+    pub developer_collective_hash: ActionHash,
+    pub page: PageInput,
+}
+/// Gets a page of the original records of the Tools of a DeveloperCollective
+#[hdk_extern]
+pub fn get_original_tools_for_developer_collective_page(
+    input: GetOriginalToolsForDeveloperCollectivePageInput,
+) -> ExternResult<Page<Record>> {
+    let links = get_links(input.page.links_input(GetLinksInputBuilder::try_new(
+        input.developer_collective_hash,
+        LinkTypes::DeveloperCollectiveToTools,
+    )?))?;
+    input.page.page_of_link_targets(links)
+}
 #[hdk_extern]
 pub fn get_deleted_tools_for_developer_collective(
     developer_collective_hash: ActionHash,
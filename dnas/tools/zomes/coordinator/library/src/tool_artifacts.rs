@@ -0,0 +1,160 @@
+use hdk::prelude::*;
+use library_integrity::*;
+use serde_bytes::ByteBuf;
+use sha2::{Digest, Sha256, Sha512};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyToolArtifactInput {
+    pub tool_hash: ActionHash, // Action hash of the Tool revision whose hashes to verify against
+    pub artifact: Artifact,
+    pub chunks: Vec<ByteBuf>, // The artifact bytes, possibly split into consecutive chunks
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum ArtifactHashVerification {
+    Match {
+        algorithm: HashAlgorithm,
+        digest: String,
+    },
+    Mismatch {
+        algorithm: HashAlgorithm,
+        expected: String,
+        actual: String,
+    },
+    /// The digest cannot be computed from the artifact bytes alone, e.g. a DnaHash
+    Unsupported { algorithm: HashAlgorithm },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyToolArtifactOutput {
+    /// true if at least one declared hash matched and none mismatched
+    pub verified: bool,
+    pub results: Vec<ArtifactHashVerification>,
+}
+
+/// Computes the digests of the given artifact bytes and compares them against every hash
+/// the Tool declares for that artifact
+#[hdk_extern]
+pub fn verify_tool_artifact(
+    input: VerifyToolArtifactInput,
+) -> ExternResult<VerifyToolArtifactOutput> {
+    let record = get(input.tool_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Tool not found".to_string())
+    ))?;
+    let tool: Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Record does not contain a Tool entry".to_string()
+        )))?;
+    let declared_hashes = match tool.hashes {
+        ToolHashes::V1(hashes) => hashes,
+        ToolHashes::Legacy(_) => {
+            return Err(wasm_error!(WasmErrorInner::Guest(
+                "Tool has unstructured legacy hashes that cannot be verified against".to_string()
+            )))
+        }
+    };
+
+    // Hash the chunks as they are, without copying the artifact into a single buffer
+    let mut digests: Vec<(ArtifactHash, Option<DigestState>)> = declared_hashes
+        .into_iter()
+        .filter(|hash| hash.artifact == input.artifact)
+        .map(|hash| {
+            let state = DigestState::new(&hash.algorithm);
+            (hash, state)
+        })
+        .collect();
+    for chunk in &input.chunks {
+        for state in digests.iter_mut().filter_map(|(_, state)| state.as_mut()) {
+            state.update(chunk);
+        }
+    }
+
+    let results: Vec<ArtifactHashVerification> = digests
+        .into_iter()
+        .map(|(hash, state)| {
+            let Some(state) = state else {
+                return ArtifactHashVerification::Unsupported {
+                    algorithm: hash.algorithm,
+                };
+            };
+            let actual = state.finalize();
+            if actual == hash.digest {
+                ArtifactHashVerification::Match {
+                    algorithm: hash.algorithm,
+                    digest: actual,
+                }
+            } else {
+                ArtifactHashVerification::Mismatch {
+                    algorithm: hash.algorithm,
+                    expected: hash.digest,
+                    actual,
+                }
+            }
+        })
+        .collect();
+
+    let verified = results
+        .iter()
+        .any(|r| matches!(r, ArtifactHashVerification::Match { .. }))
+        && !results
+            .iter()
+            .any(|r| matches!(r, ArtifactHashVerification::Mismatch { .. }));
+
+    Ok(VerifyToolArtifactOutput { verified, results })
+}
+
+/// Computes the lowercase hex digest of the given bytes, in the same encoding as
+/// ArtifactHash digests are published in
+pub(crate) fn compute_digest(
+    algorithm: &HashAlgorithm,
+    bytes: &[u8],
+) -> ExternResult<Option<String>> {
+    Ok(DigestState::new(algorithm).map(|mut state| {
+        state.update(bytes);
+        state.finalize()
+    }))
+}
+
+/// A digest being computed over bytes that are fed in piece by piece
+enum DigestState {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Blake2b256(blake2b_simd::State),
+}
+
+impl DigestState {
+    /// None if the digest cannot be computed from the bytes alone, e.g. a DnaHash
+    fn new(algorithm: &HashAlgorithm) -> Option<Self> {
+        match algorithm {
+            HashAlgorithm::Sha256 => Some(Self::Sha256(Sha256::new())),
+            HashAlgorithm::Sha512 => Some(Self::Sha512(Sha512::new())),
+            HashAlgorithm::Blake2b256 => Some(Self::Blake2b256(
+                blake2b_simd::Params::new().hash_length(32).to_state(),
+            )),
+            HashAlgorithm::HolochainDnaHash => None,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(bytes),
+            Self::Sha512(hasher) => hasher.update(bytes),
+            Self::Blake2b256(state) => {
+                state.update(bytes);
+            }
+        }
+    }
+
+    fn finalize(self) -> String {
+        let digest = match self {
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+            Self::Sha512(hasher) => hasher.finalize().to_vec(),
+            Self::Blake2b256(state) => state.finalize().as_bytes().to_vec(),
+        };
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
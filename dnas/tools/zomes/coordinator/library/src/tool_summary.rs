@@ -0,0 +1,137 @@
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::icon::resolve_icon;
+use crate::index_shard::get_index_links_for_page;
+use crate::pagination::{Page, PageInput};
+use crate::tool::get_latest_tools;
+
+/// The fields of the latest revision of a Tool that are needed to list it, without the icon
+/// itself. The icon can be fetched separately with `get_icon`, or with `get_tool_icon` for
+/// Tools whose latest revision still has its icon inline.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ToolSummary {
+    pub original_tool_hash: ActionHash,
+    pub tool_hash: ActionHash,
+    pub developer_collective: ActionHash,
+    pub title: String,
+    pub subtitle: String,
+    pub version: String,
+    pub channel: ReleaseChannel,
+    pub tags: Vec<String>,
+    pub icon_hash: Option<EntryHash>, // None if the icon is inline
+    pub deprecated: bool,
+}
+
+fn tool_summary(
+    original_tool_hash: ActionHash,
+    record: &Record,
+) -> ExternResult<Option<ToolSummary>> {
+    let Some(tool) = record
+        .entry()
+        .to_app_option::<Tool>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(None);
+    };
+    Ok(Some(ToolSummary {
+        original_tool_hash,
+        tool_hash: record.action_address().clone(),
+        developer_collective: tool.developer_collective,
+        title: tool.title,
+        subtitle: tool.subtitle,
+        version: tool.version,
+        channel: tool.channel,
+        tags: tool.tags,
+        icon_hash: match tool.icon {
+            IconRef::Hash(icon_hash) => Some(icon_hash),
+            IconRef::Legacy(_) => None,
+        },
+        deprecated: tool.deprecation.is_some(),
+    }))
+}
+
+/// Summarizes the latest revisions of the given Tools, skipping Tools that have been deleted
+fn latest_tool_summaries(links: Vec<Link>) -> ExternResult<Vec<ToolSummary>> {
+    let mut original_tool_hashes: Vec<ActionHash> = vec![];
+    for link in links {
+        if let Some(original_tool_hash) = link.target.into_action_hash() {
+            if !original_tool_hashes.contains(&original_tool_hash) {
+                original_tool_hashes.push(original_tool_hash);
+            }
+        }
+    }
+    let get_details_input: Vec<GetInput> = original_tool_hashes
+        .iter()
+        .map(|hash| GetInput::new(hash.clone().into(), GetOptions::default()))
+        .collect();
+    let details = HDK.with(|hdk| hdk.borrow().get_details(get_details_input))?;
+    let original_tool_hashes: Vec<ActionHash> = original_tool_hashes
+        .into_iter()
+        .zip(details)
+        .filter_map(|(original_tool_hash, details)| match details {
+            Some(Details::Record(details)) if details.deletes.is_empty() => {
+                Some(original_tool_hash)
+            }
+            _ => None,
+        })
+        .collect();
+    let latest_tools = get_latest_tools(&original_tool_hashes)?;
+    let mut summaries = vec![];
+    for (original_tool_hash, record) in original_tool_hashes.into_iter().zip(latest_tools) {
+        let Some(record) = record else {
+            continue;
+        };
+        if let Some(summary) = tool_summary(original_tool_hash, &record)? {
+            summaries.push(summary);
+        }
+    }
+    Ok(summaries)
+}
+
+/// Gets summaries of the latest revisions of all Tools of a DeveloperCollective
+#[hdk_extern]
+pub fn get_tool_summaries_for_developer_collective(
+    developer_collective_hash: ActionHash,
+) -> ExternResult<Vec<ToolSummary>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(
+            developer_collective_hash,
+            LinkTypes::DeveloperCollectiveToTools,
+        )?
+        .build(),
+    )?;
+    latest_tool_summaries(links)
+}
+
+/// Gets summaries of the latest revisions of all Tools of a Curator
+#[hdk_extern]
+pub fn get_tool_summaries_for_curator(curator_hash: ActionHash) -> ExternResult<Vec<ToolSummary>> {
+    let links =
+        get_links(GetLinksInputBuilder::try_new(curator_hash, LinkTypes::CuratorToTools)?.build())?;
+    latest_tool_summaries(links)
+}
+
+/// Gets a page of summaries of the latest revisions of all Tools of all DeveloperCollectives
+#[hdk_extern]
+pub fn get_all_tool_summaries_page(page: PageInput) -> ExternResult<Page<ToolSummary>> {
+    let links = get_index_links_for_page("all_tools", LinkTypes::AllTools, &page)?;
+    let links_page = page.page_of_links(links);
+    Ok(Page {
+        items: latest_tool_summaries(links_page.items)?,
+        next: links_page.next,
+    })
+}
+
+/// Gets the icon of a specific revision of a Tool
+#[hdk_extern]
+pub fn get_tool_icon(tool_hash: ActionHash) -> ExternResult<Option<Icon>> {
+    let Some(record) = get(tool_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    let tool: Option<Tool> = record.entry().to_app_option().map_err(|e| wasm_error!(e))?;
+    match tool {
+        Some(tool) => resolve_icon(&tool.icon),
+        None => Ok(None),
+    }
+}
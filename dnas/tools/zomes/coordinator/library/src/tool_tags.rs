@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+
+use hdk::prelude::*;
+use library_integrity::*;
+
+use crate::ownership_transfer::get_owner_permissions;
+use crate::tool::get_latest_tool;
+
+/// Moves a Tool between tag paths when a revision changes its tags: the Tool is linked from the
+/// tags that have been added, and its links from the tags that have been removed are deleted.
+/// Pass no `tool` when the Tool is deleted to remove it from all tags of `previous_tool`.
+pub(crate) fn update_tag_links(
+    original_tool_hash: &ActionHash,
+    tool_hash: &ActionHash,
+    tool: Option<&Tool>,
+    previous_tool: Option<&Tool>,
+) -> ExternResult<()> {
+    let tags: BTreeSet<&String> = tool.iter().flat_map(|tool| &tool.tags).collect();
+    let previous_tags: BTreeSet<&String> =
+        previous_tool.iter().flat_map(|tool| &tool.tags).collect();
+    for tag in tags.difference(&previous_tags) {
+        create_link(
+            tag_path(tag).path_entry_hash()?,
+            original_tool_hash.clone(),
+            LinkTypes::TagToTools,
+            tag_to_tools_link_tag(tool_hash, tag),
+        )?;
+        register_tag(tag)?;
+    }
+    let removed_tags: Vec<&&String> = previous_tags.difference(&tags).collect();
+    if removed_tags.is_empty() {
+        return Ok(());
+    }
+    // Links created by other contributors can only be deleted by owners, get_tools_for_tag
+    // skips Tools whose latest revision doesn't have the tag anymore either way
+    let my_agent = agent_info()?.agent_initial_pubkey;
+    let is_owner = match tool.or(previous_tool) {
+        Some(tool) => get_owner_permissions(tool.developer_collective.clone())?
+            .iter()
+            .any(|(owner, _)| owner == &my_agent),
+        None => false,
+    };
+    for tag in removed_tags {
+        let links = get_links(
+            GetLinksInputBuilder::try_new(tag_path(tag).path_entry_hash()?, LinkTypes::TagToTools)?
+                .build(),
+        )?;
+        for link in links {
+            if link.target.clone().into_action_hash().as_ref() == Some(original_tool_hash)
+                && (link.author == my_agent || is_owner)
+            {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds the tag to the list of all tags if it isn't listed yet
+fn register_tag(tag: &str) -> ExternResult<()> {
+    let all_tags_hash = Path::from("all_tags").path_entry_hash()?;
+    let links = get_links(
+        GetLinksInputBuilder::try_new(all_tags_hash.clone(), LinkTypes::AllTags)?
+            .tag_prefix(LinkTag::new(tag.as_bytes()))
+            .build(),
+    )?;
+    if links.iter().any(|link| link.tag.0 == tag.as_bytes()) {
+        return Ok(());
+    }
+    create_link(
+        all_tags_hash,
+        tag_path(tag).path_entry_hash()?,
+        LinkTypes::AllTags,
+        LinkTag::new(tag.as_bytes()),
+    )?;
+    Ok(())
+}
+
+/// Gets the latest revisions of the Tools that currently have the tag
+#[hdk_extern]
+pub fn get_tools_for_tag(tag: String) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(tag_path(&tag).path_entry_hash()?, LinkTypes::TagToTools)?
+            .build(),
+    )?;
+    let mut original_tool_hashes: Vec<ActionHash> = vec![];
+    for link in links {
+        if let Some(original_tool_hash) = link.target.into_action_hash() {
+            if !original_tool_hashes.contains(&original_tool_hash) {
+                original_tool_hashes.push(original_tool_hash);
+            }
+        }
+    }
+    let get_details_input: Vec<GetInput> = original_tool_hashes
+        .iter()
+        .map(|hash| GetInput::new(hash.clone().into(), GetOptions::default()))
+        .collect();
+    let details = HDK.with(|hdk| hdk.borrow().get_details(get_details_input))?;
+    let mut records = vec![];
+    for (original_tool_hash, details) in original_tool_hashes.into_iter().zip(details) {
+        // Deleted Tools are not listed anymore
+        let Some(Details::Record(details)) = details else {
+            continue;
+        };
+        if !details.deletes.is_empty() {
+            continue;
+        }
+        let Some(record) = get_latest_tool(original_tool_hash)? else {
+            continue;
+        };
+        let Some(tool) = record
+            .entry()
+            .to_app_option::<Tool>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if tool.tags.contains(&tag) {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Gets all tags that Tools have been published with, sorted alphabetically
+#[hdk_extern]
+pub fn get_all_tags(_: ()) -> ExternResult<Vec<String>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(
+            Path::from("all_tags").path_entry_hash()?,
+            LinkTypes::AllTags,
+        )?
+        .build(),
+    )?;
+    let tags: BTreeSet<String> = links
+        .into_iter()
+        .filter_map(|link| String::from_utf8(link.tag.0).ok())
+        .collect();
+    Ok(tags.into_iter().collect())
+}
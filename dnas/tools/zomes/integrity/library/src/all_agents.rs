@@ -1,21 +1,18 @@
 use hdi::prelude::*;
 
+use crate::is_index_base;
+
 pub fn validate_create_link_all_agents(
     action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Check that base address is pointing away from the all_agents anchor
-    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
-        wasm_error!(WasmErrorInner::Guest(
-            "Base address is not an entry hash".into()
-        ))
-    })?;
-    let path = Path::from("all_agents");
-    if path.path_entry_hash()? != base_address_entry_hash {
+    // Check that base address is pointing away from the shard of the all_agents anchor
+    if !is_index_base("all_agents", &base_address, &target_address)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "AllAgents link is not pointing away from the all_agents anchor.".into(),
+            "AllAgents link is not pointing away from the all_agents anchor shard of its target."
+                .into(),
         ));
     }
 
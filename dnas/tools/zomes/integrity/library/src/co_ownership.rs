@@ -0,0 +1,350 @@
+use hdi::prelude::*;
+
+use std::collections::HashSet;
+
+use crate::{
+    ownership_transfer_from_record, permission_revocation_from_record, EntryTypes, LinkTypes,
+    UnitEntryTypes,
+};
+
+/// Appoints an agent as co-owner of a DeveloperCollective. Co-owners have the same rights as
+/// the creator of the collective (issuing permissions, updating the collective and deleting
+/// tools) once they have accepted the co-ownership by creating an OwnerToDeveloperCollective
+/// link that carries the action hash of this entry in its tag. Co-owners are removed with a
+/// PermissionRevocation of this entry.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CoOwnership {
+    pub for_collective: ActionHash,
+    pub for_agent: AgentPubKey,
+}
+
+/// Rules:
+/// 1. Only owners of the developer collective can appoint co-owners
+/// 2. The creator of the developer collective cannot be appointed as co-owner
+pub fn validate_create_co_ownership(
+    action: EntryCreationAction,
+    co_ownership: CoOwnership,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(co_ownership.for_collective.clone())?;
+    let _developer_collective: crate::DeveloperCollective = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    if record.action().author() == &co_ownership.for_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The creator of the developer collective is already an owner of it.".into(),
+        ));
+    }
+    if !is_developer_collective_owner(
+        action.author(),
+        &co_ownership.for_collective,
+        action.prev_action(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only owners of the developer collective can appoint co-owners.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_co_ownership(
+    _action: Update,
+    _co_ownership: CoOwnership,
+    _original_action: EntryCreationAction,
+    _original_co_ownership: CoOwnership,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CoOwnerships cannot be updated",
+    )))
+}
+pub fn validate_delete_co_ownership(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_co_ownership: CoOwnership,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CoOwnerships cannot be deleted, co-owners are removed with a PermissionRevocation",
+    )))
+}
+/// Rules
+/// 1. Only the agent that created the CoOwnership entry can link it from the DeveloperCollective
+/// 2. Links from a DeveloperCollective can only point to a CoOwnership for that same
+///    DeveloperCollective
+/// 3. The link tag must contain the AgentPubKey of the co-owner
+pub fn validate_create_link_developer_collective_to_co_owners(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let developer_collective_action_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let co_ownership_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let co_ownership_record = must_get_valid_record(co_ownership_action_hash)?;
+    if co_ownership_record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that appointed a co-owner can link the CoOwnership from the developer collective."
+                .into(),
+        ));
+    }
+    let Some(co_ownership) = co_ownership_from_record(&co_ownership_record)? else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link target must be a CoOwnership entry.".into(),
+        ));
+    };
+    if co_ownership.for_collective != developer_collective_action_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links from developer collectives can only point to co-ownerships for that same developer collective."
+                .into(),
+        ));
+    }
+    let agent_in_tag = AgentPubKey::from_raw_39(tag.0).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Link tag does not contain a valid agent public key".into()
+        ))
+    })?;
+    if agent_in_tag != co_ownership.for_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag contains the wrong agent public key.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Links to co-owners can be deleted by the co-owner themselves or by an owner of the
+///    developer collective
+pub fn validate_delete_link_developer_collective_to_co_owners(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AgentPubKey::from_raw_39(tag.0).ok().as_ref() == Some(&action.author) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let developer_collective_action_hash =
+        base.into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    if is_developer_collective_owner(
+        &action.author,
+        &developer_collective_action_hash,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only owners of the developer collective can remove links to co-owners.".into(),
+    ))
+}
+
+/// Whether `agent` is an owner of the DeveloperCollective as of `chain_top` on the agent's own
+/// source chain. Ownership is determined by walking the agent's source chain back to the
+/// creation of the collective:
+/// - the creator of the collective starts out as owner
+/// - accepting a CoOwnership or OwnershipTransfer for the collective, by creating an
+///   OwnerToDeveloperCollective link that carries its action hash in the tag, makes the agent owner
+/// - offering an OwnershipTransfer of the collective to another agent ends the ownership, and
+///   reclaiming the collective after the recipient declined the transfer restores it
+/// - revoking their own CoOwnership for the collective ends the ownership
+/// - acknowledging that another owner revoked the CoOwnership that the agent accepted ends the
+///   ownership, and the CoOwnership can't be accepted again
+///
+/// Owner links that have been deleted are ignored, as are transfer offers that the offering
+/// agent deleted on their own chain before only the recipient could decline them.
+/// A co-owner that hasn't acknowledged the revocation of their CoOwnership yet stays owner in
+/// validation, except for actions that act with the revocation as permission.
+pub fn is_developer_collective_owner(
+    agent: &AgentPubKey,
+    developer_collective_hash: &ActionHash,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    let developer_collective_record = must_get_valid_record(developer_collective_hash.clone())?;
+    // The record that makes the agent owner, the collective itself for its creator
+    let mut grant: Option<ActionHash> = (developer_collective_record.action().author() == agent)
+        .then(|| developer_collective_hash.clone());
+    let mut revoked_grants: HashSet<ActionHash> = HashSet::new();
+
+    // Owner links can't be older than the collective, so older actions are not relevant
+    let activity = agent_activity_since(
+        agent,
+        chain_top,
+        developer_collective_record.action().timestamp(),
+    )?;
+    let deleted: HashSet<&ActionHash> = activity
+        .iter()
+        .filter_map(|a| match a.action.action() {
+            Action::DeleteLink(delete_link) => Some(&delete_link.link_add_address),
+            Action::Delete(delete) => Some(&delete.deletes_address),
+            _ => None,
+        })
+        .collect();
+    for a in activity.iter() {
+        if deleted.contains(a.action.action_address()) {
+            continue;
+        }
+        match a.action.action() {
+            Action::CreateLink(create_link) => {
+                let Ok(grant_hash) = ActionHash::from_raw_39(create_link.tag.0.clone()) else {
+                    continue;
+                };
+                match LinkTypes::from_type(create_link.zome_index, create_link.link_type)? {
+                    Some(LinkTypes::RevocationAcknowledgements) => {
+                        if grant.as_ref() == Some(&grant_hash) {
+                            grant = None;
+                        }
+                        revoked_grants.insert(grant_hash);
+                    }
+                    Some(LinkTypes::OwnerToDeveloperCollective)
+                        if create_link.target_address
+                            == developer_collective_hash.clone().into()
+                            && !revoked_grants.contains(&grant_hash) =>
+                    {
+                        let grant_record = must_get_valid_record(grant_hash.clone())?;
+                        if let Some((for_collective, for_agent)) =
+                            owner_grant_from_record(&grant_record)?
+                        {
+                            if &for_agent == agent && &for_collective == developer_collective_hash {
+                                grant = Some(grant_hash);
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            Action::Create(create) => {
+                let EntryType::App(app_entry_def) = &create.entry_type else {
+                    continue;
+                };
+                match UnitEntryTypes::try_from(ScopedEntryDefIndex {
+                    zome_index: app_entry_def.zome_index,
+                    zome_type: app_entry_def.entry_index,
+                }) {
+                    Ok(UnitEntryTypes::OwnershipTransfer) => {
+                        let record = must_get_valid_record(a.action.action_address().clone())?;
+                        if let Some(ownership_transfer) = ownership_transfer_from_record(&record)? {
+                            if &ownership_transfer.for_collective == developer_collective_hash {
+                                grant = None;
+                            }
+                        }
+                    }
+                    Ok(UnitEntryTypes::PermissionRevocation) => {
+                        let record = must_get_valid_record(a.action.action_address().clone())?;
+                        let Some(permission_revocation) =
+                            permission_revocation_from_record(&record)?
+                        else {
+                            continue;
+                        };
+                        let revoked_record =
+                            must_get_valid_record(permission_revocation.permission)?;
+                        if let Some(co_ownership) = co_ownership_from_record(&revoked_record)? {
+                            if &co_ownership.for_agent == agent
+                                && &co_ownership.for_collective == developer_collective_hash
+                            {
+                                grant = None;
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(grant.is_some())
+}
+
+const AGENT_ACTIVITY_PAGE_SIZE: u32 = 100;
+
+/// Gets the actions of the agent's source chain from `chain_top` back to the first action that
+/// was created before `since`, ordered by their sequence number. The chain is fetched in pages
+/// so that validation only retrieves the part of the chain it needs.
+pub fn agent_activity_since(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    since: Timestamp,
+) -> ExternResult<Vec<RegisterAgentActivity>> {
+    let mut activity = vec![];
+    let mut page_top = Some(chain_top.clone());
+    while let Some(top) = page_top.take() {
+        let mut page = must_get_agent_activity(
+            agent.clone(),
+            ChainFilter::new(top).take(AGENT_ACTIVITY_PAGE_SIZE),
+        )?;
+        page.sort_by_key(|a| std::cmp::Reverse(a.action.action().action_seq()));
+        let page_len = page.len();
+        for a in page {
+            if a.action.action().timestamp() < since {
+                page_top = None;
+                break;
+            }
+            page_top = a.action.action().prev_action().cloned();
+            activity.push(a);
+        }
+        if page_len < AGENT_ACTIVITY_PAGE_SIZE as usize {
+            break;
+        }
+    }
+    activity.reverse();
+    Ok(activity)
+}
+
+/// Gets the DeveloperCollective and the agent of a record that grants owner rights, i.e. a
+/// CoOwnership, an OwnershipTransfer, or the Delete with which the recipient declined an
+/// OwnershipTransfer, which hands the collective back to the agent that offered it
+pub fn owner_grant_from_record(record: &Record) -> ExternResult<Option<(ActionHash, AgentPubKey)>> {
+    if let Action::Delete(delete) = record.action() {
+        let ownership_transfer_record = must_get_valid_record(delete.deletes_address.clone())?;
+        return Ok(
+            ownership_transfer_from_record(&ownership_transfer_record)?.map(|ownership_transfer| {
+                (
+                    ownership_transfer.for_collective,
+                    ownership_transfer_record.action().author().clone(),
+                )
+            }),
+        );
+    }
+    if let Some(co_ownership) = co_ownership_from_record(record)? {
+        return Ok(Some((co_ownership.for_collective, co_ownership.for_agent)));
+    }
+    if let Some(ownership_transfer) = ownership_transfer_from_record(record)? {
+        return Ok(Some((
+            ownership_transfer.for_collective,
+            ownership_transfer.to_agent,
+        )));
+    }
+    Ok(None)
+}
+
+/// Deserializes the record into a CoOwnership only if it actually is of that entry type.
+/// A ContributorPermission entry would otherwise deserialize into a CoOwnership as well.
+pub fn co_ownership_from_record(record: &Record) -> ExternResult<Option<CoOwnership>> {
+    match app_entry_from_record(record)? {
+        Some(EntryTypes::CoOwnership(co_ownership)) => Ok(Some(co_ownership)),
+        _ => Ok(None),
+    }
+}
+
+/// Deserializes the entry of a record according to the entry type of its action
+pub fn app_entry_from_record(record: &Record) -> ExternResult<Option<EntryTypes>> {
+    let (Some(EntryType::App(app_entry_def)), Some(entry)) =
+        (record.action().entry_type(), record.entry().as_option())
+    else {
+        return Ok(None);
+    };
+    EntryTypes::deserialize_from_type(app_entry_def.zome_index, app_entry_def.entry_index, entry)
+}
@@ -0,0 +1,328 @@
+use hdi::prelude::*;
+
+use crate::{
+    agent_activity_since, app_entry_from_record, is_developer_collective_owner,
+    validate_tool_scope, ContributorPermission, ContributorRole, EntryTypes, UnitEntryTypes,
+};
+
+/// Invitation of an owner for an agent to contribute to a DeveloperCollective. The invited agent
+/// accepts it by creating a ContributorPermission with the same fields that references this
+/// invitation, and declines it by deleting it. Both responses are made on the invited agent's
+/// own source chain, so validation can ensure that an invitation is responded to only once.
+/// Owners withdraw an invitation with a PermissionRevocation of it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ContributorInvitation {
+    pub for_collective: ActionHash,
+    pub for_agent: AgentPubKey,
+    pub expiry: Option<Timestamp>,
+    pub role: ContributorRole,
+    pub tools: Option<Vec<ActionHash>>,
+}
+
+impl ContributorInvitation {
+    /// Whether the ContributorPermission grants exactly what this invitation offers
+    pub fn matches(&self, contributor_permission: &ContributorPermission) -> bool {
+        self.for_collective == contributor_permission.for_collective
+            && self.for_agent == contributor_permission.for_agent
+            && self.expiry == contributor_permission.expiry
+            && self.role == contributor_permission.role
+            && self.tools == contributor_permission.tools
+    }
+}
+
+/// Rules:
+/// 1. Only owners of the developer collective can invite contributors to it
+/// 2. The creator of the developer collective cannot be invited
+/// 3. A tool scope may not be empty and may only contain Tools of the developer collective
+pub fn validate_create_contributor_invitation(
+    action: EntryCreationAction,
+    contributor_invitation: ContributorInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(contributor_invitation.for_collective.clone())?;
+    let _developer_collective: crate::DeveloperCollective = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    if record.action().author() == &contributor_invitation.for_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The creator of the developer collective cannot be invited as contributor.".into(),
+        ));
+    }
+    if !is_developer_collective_owner(
+        action.author(),
+        &contributor_invitation.for_collective,
+        action.prev_action(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only owners of the developer collective can invite contributors to it.".into(),
+        ));
+    }
+    validate_tool_scope(
+        &contributor_invitation.for_collective,
+        &contributor_invitation.tools,
+    )
+}
+pub fn validate_update_contributor_invitation(
+    _action: Update,
+    _contributor_invitation: ContributorInvitation,
+    _original_action: EntryCreationAction,
+    _original_contributor_invitation: ContributorInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ContributorInvitations cannot be updated",
+    )))
+}
+/// Rules:
+/// 1. Only the invited agent can decline an invitation, owners withdraw it with a
+///    PermissionRevocation
+/// 2. The decline may not be older than the invitation
+/// 3. The invited agent can only decline an invitation they have not accepted or declined before
+pub fn validate_delete_contributor_invitation(
+    action: Delete,
+    original_action: EntryCreationAction,
+    original_contributor_invitation: ContributorInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_contributor_invitation.for_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the invited agent can decline an invitation.".into(),
+        ));
+    }
+    if &action.timestamp < original_action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An invitation cannot be declined before it has been created.".into(),
+        ));
+    }
+    if has_responded_to_contributor_invitation(
+        &action.author,
+        &action.deletes_address,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The contributor invitation has already been accepted or declined.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Only the agent that created the invitation can link it from the DeveloperCollective
+/// 2. Links from a DeveloperCollective can only point to invitations to that same
+///    DeveloperCollective
+/// 3. The link tag must contain the AgentPubKey of the invited agent
+pub fn validate_create_link_developer_collective_to_contributor_invitations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let developer_collective_action_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let Some(contributor_invitation) = contributor_invitation_for_link(&action, target_address)?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that created a contributor invitation can create links to it.".into(),
+        ));
+    };
+    if contributor_invitation.for_collective != developer_collective_action_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links from developer collectives can only point to invitations to that same developer collective."
+                .into(),
+        ));
+    }
+    let agent_in_tag = AgentPubKey::from_raw_39(tag.0).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Link tag does not contain a valid agent public key".into()
+        ))
+    })?;
+    if agent_in_tag != contributor_invitation.for_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag contains the wrong agent public key.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Links to invitations can be deleted by the agent that created the link, the invited agent
+///    or an owner of the developer collective
+pub fn validate_delete_link_developer_collective_to_contributor_invitations(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author
+        || AgentPubKey::from_raw_39(tag.0).ok().as_ref() == Some(&action.author)
+    {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let developer_collective_action_hash =
+        base.into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    if is_developer_collective_owner(
+        &action.author,
+        &developer_collective_action_hash,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the invited agent or owners of the developer collective can delete links to invitations."
+            .into(),
+    ))
+}
+/// Rules
+/// 1. Only the agent that created the invitation can link it from the invited agent
+/// 2. Links from an agent can only point to invitations for that same agent
+/// 3. The link tag must contain the action hash of the DeveloperCollective
+pub fn validate_create_link_invitee_to_contributor_invitations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let invitee = base_address
+        .into_agent_pub_key()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No agent public key associated with link base".to_string()
+        )))?;
+    let Some(contributor_invitation) = contributor_invitation_for_link(&action, target_address)?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that created a contributor invitation can create links to it.".into(),
+        ));
+    };
+    if contributor_invitation.for_agent != invitee {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links from agents can only point to invitations for that same agent.".into(),
+        ));
+    }
+    let collective_in_tag = ActionHash::from_raw_39(tag.0).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Link tag does not contain a valid action hash".into()
+        ))
+    })?;
+    if collective_in_tag != contributor_invitation.for_collective {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not contain the action hash of the DeveloperCollective of the invitation."
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Links to invitations can be deleted by the agent that created the link, the invited agent
+///    or an owner of the developer collective
+pub fn validate_delete_link_invitee_to_contributor_invitations(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author
+        || base.into_agent_pub_key().as_ref() == Some(&action.author)
+    {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    if let Ok(developer_collective_hash) = ActionHash::from_raw_39(tag.0) {
+        if is_developer_collective_owner(
+            &action.author,
+            &developer_collective_hash,
+            &action.prev_action,
+        )? {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the invited agent, owners of the developer collective or the agent that created the link can delete links to invitations."
+            .into(),
+    ))
+}
+
+/// Whether the invited agent has accepted or declined the invitation on their own source chain
+/// as of `chain_top`, i.e. created a ContributorPermission that references the invitation, or
+/// deleted the invitation
+pub fn has_responded_to_contributor_invitation(
+    invitee: &AgentPubKey,
+    contributor_invitation_hash: &ActionHash,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    let contributor_invitation_record = must_get_valid_record(contributor_invitation_hash.clone())?;
+    // Neither response can be older than the invitation
+    let activity = agent_activity_since(
+        invitee,
+        chain_top,
+        contributor_invitation_record.action().timestamp(),
+    )?;
+    for a in activity.iter() {
+        let responded = match a.action.action() {
+            Action::Create(create) => {
+                let EntryType::App(app_entry_def) = &create.entry_type else {
+                    continue;
+                };
+                if !matches!(
+                    UnitEntryTypes::try_from(ScopedEntryDefIndex {
+                        zome_index: app_entry_def.zome_index,
+                        zome_type: app_entry_def.entry_index,
+                    }),
+                    Ok(UnitEntryTypes::ContributorPermission)
+                ) {
+                    continue;
+                }
+                let record = must_get_valid_record(a.action.action_address().clone())?;
+                matches!(
+                    app_entry_from_record(&record)?,
+                    Some(EntryTypes::ContributorPermission(contributor_permission))
+                        if contributor_permission.invitation.as_ref()
+                            == Some(contributor_invitation_hash)
+                )
+            }
+            Action::Delete(delete) => &delete.deletes_address == contributor_invitation_hash,
+            _ => false,
+        };
+        if responded {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Gets the ContributorInvitation a link points to, if it has been created by the author of the link
+fn contributor_invitation_for_link(
+    action: &CreateLink,
+    target_address: AnyLinkableHash,
+) -> ExternResult<Option<ContributorInvitation>> {
+    let contributor_invitation_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(contributor_invitation_action_hash)?;
+    if record.action().author() != &action.author {
+        return Ok(None);
+    }
+    contributor_invitation_from_record(&record)
+}
+
+/// Deserializes the record into a ContributorInvitation only if it actually is of that entry type
+pub fn contributor_invitation_from_record(
+    record: &Record,
+) -> ExternResult<Option<ContributorInvitation>> {
+    match app_entry_from_record(record)? {
+        Some(EntryTypes::ContributorInvitation(contributor_invitation)) => {
+            Ok(Some(contributor_invitation))
+        }
+        _ => Ok(None),
+    }
+}
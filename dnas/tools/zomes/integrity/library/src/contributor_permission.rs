@@ -1,23 +1,124 @@
 use hdi::prelude::*;
+
+use crate::{
+    agent_activity_since, app_entry_from_record, has_responded_to_contributor_invitation,
+    is_deleted_by_owner, is_developer_collective_owner, permission_revocation_from_record,
+    EntryTypes, UnitEntryTypes,
+};
+
+/// What a contributor is allowed to do with the Tools of a DeveloperCollective
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ContributorRole {
+    /// Can publish new Tools and update every field of existing Tools
+    #[default]
+    Maintainer,
+    /// Can publish new releases of existing Tools, but not publish new Tools
+    Publisher,
+    /// Can only edit the descriptive metadata of existing Tools (title, subtitle, description,
+    /// icon and meta_data), not their source, hashes, version, channel, changelog or deprecation
+    MetadataEditor,
+}
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct ContributorPermission {
     pub for_collective: ActionHash,
     pub for_agent: AgentPubKey,
     pub expiry: Option<Timestamp>,
+    #[serde(default)] // ContributorPermissions issued before roles existed are for maintainers
+    pub role: ContributorRole,
+    /// Action hashes of the Create actions of the Tools that the permission is restricted to.
+    /// Permissions without such a scope apply to all Tools of the DeveloperCollective.
+    #[serde(default)]
+    pub tools: Option<Vec<ActionHash>>,
+    /// The ContributorInvitation that the contributor accepted by creating this permission.
+    /// Permissions issued directly by owners before invitations existed don't have one, new
+    /// permissions either accept an invitation or renew a permission.
+    #[serde(default)]
+    pub invitation: Option<ActionHash>,
+    /// The ContributorPermission that this permission extends the expiry of
+    #[serde(default)]
+    pub renews: Option<ActionHash>,
 }
+/// Rules:
+/// 1. A contributor permission either accepts a ContributorInvitation or renews a contributor
+///    permission
+/// 2. A contributor permission that accepts an invitation is created by the invited agent, must
+///    match the invitation and may not be older than it. Each invitation can only be accepted
+///    once, and not after it has been declined
+/// 3. The creator of the developer collective cannot be issued a contributor permission
+/// 4. A tool scope may not be empty and may only contain Tools of the developer collective
+/// 5. A renewal is issued by an owner and may only extend the expiry of an expiring permission,
+///    all other fields must stay the same
+/// 6. An owner cannot renew a permission that they revoked, or a renewal of such a permission
 pub fn validate_create_contributor_permission(
     action: EntryCreationAction,
     contributor_permission: ContributorPermission,
 ) -> ExternResult<ValidateCallbackResult> {
     let record = must_get_valid_record(contributor_permission.for_collective.clone())?;
-    if record.action().author() != action.author() {
-        return Ok(
-            ValidateCallbackResult::Invalid(
-                "Only the creator of the developer collective can issue contributor permissions for it."
+    if let (Some(_), Some(_)) = (
+        &contributor_permission.invitation,
+        &contributor_permission.renews,
+    ) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A renewal of a contributor permission cannot accept an invitation.".into(),
+        ));
+    }
+    if let Some(invitation_hash) = &contributor_permission.invitation {
+        if action.author() != &contributor_permission.for_agent {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Only the invited agent can accept a contributor invitation.".into(),
+            ));
+        }
+        let invitation_record = must_get_valid_record(invitation_hash.clone())?;
+        let Some(EntryTypes::ContributorInvitation(invitation)) =
+            app_entry_from_record(&invitation_record)?
+        else {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The invitation of a contributor permission must be a ContributorInvitation."
                     .into(),
-            ),
-        );
+            ));
+        };
+        if !invitation.matches(&contributor_permission) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Contributor permission does not match the invitation it accepts.".into(),
+            ));
+        }
+        if *action.timestamp() < invitation_record.action().timestamp() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "An invitation cannot be accepted before it has been created.".into(),
+            ));
+        }
+        if has_responded_to_contributor_invitation(
+            action.author(),
+            invitation_hash,
+            action.prev_action(),
+        )? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The contributor invitation has already been accepted or declined.".into(),
+            ));
+        }
+    } else if let Some(previous_permission_hash) = &contributor_permission.renews {
+        if !is_developer_collective_owner(
+            action.author(),
+            &contributor_permission.for_collective,
+            action.prev_action(),
+        )? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Only owners of the developer collective can renew contributor permissions for it."
+                    .into(),
+            ));
+        }
+        if let ValidateCallbackResult::Invalid(e) =
+            validate_renewal(&action, previous_permission_hash, &contributor_permission)?
+        {
+            return Ok(ValidateCallbackResult::Invalid(e));
+        }
+    } else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A contributor permission must either accept an invitation or renew a contributor permission."
+                .into(),
+        ));
     }
     if record.action().author() == &contributor_permission.for_agent {
         return Ok(
@@ -34,6 +135,147 @@ pub fn validate_create_contributor_permission(
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Dependant action must be accompanied by an entry"
         ))))?;
+    validate_tool_scope(
+        &contributor_permission.for_collective,
+        &contributor_permission.tools,
+    )
+}
+fn validate_renewal(
+    action: &EntryCreationAction,
+    previous_permission_hash: &ActionHash,
+    contributor_permission: &ContributorPermission,
+) -> ExternResult<ValidateCallbackResult> {
+    let previous_record = must_get_valid_record(previous_permission_hash.clone())?;
+    let Some(EntryTypes::ContributorPermission(previous_permission)) =
+        app_entry_from_record(&previous_record)?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only contributor permissions can be renewed.".into(),
+        ));
+    };
+    if previous_permission.for_collective != contributor_permission.for_collective
+        || previous_permission.for_agent != contributor_permission.for_agent
+        || previous_permission.role != contributor_permission.role
+        || previous_permission.tools != contributor_permission.tools
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A renewal may only change the expiry of a contributor permission.".into(),
+        ));
+    }
+    let Some(previous_expiry) = previous_permission.expiry else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Contributor permissions without expiry cannot be renewed.".into(),
+        ));
+    };
+    if let Some(expiry) = contributor_permission.expiry {
+        if expiry <= previous_expiry {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A renewal must extend the expiry of the contributor permission.".into(),
+            ));
+        }
+    }
+    if has_revoked_permission_lineage(action, previous_permission_hash, &previous_record)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A revoked contributor permission cannot be renewed.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Whether the author of the action has revoked the permission or any of the permissions it
+/// renews on their own source chain. Revocations by other owners are not on the author's chain,
+/// coordinators check for them before renewing.
+fn has_revoked_permission_lineage(
+    action: &EntryCreationAction,
+    permission_hash: &ActionHash,
+    permission_record: &Record,
+) -> ExternResult<bool> {
+    let mut lineage = vec![permission_hash.clone()];
+    let mut oldest_record = permission_record.clone();
+    while let Some(EntryTypes::ContributorPermission(ContributorPermission {
+        renews: Some(renewed_permission_hash),
+        ..
+    })) = app_entry_from_record(&oldest_record)?
+    {
+        oldest_record = must_get_valid_record(renewed_permission_hash.clone())?;
+        lineage.push(renewed_permission_hash);
+    }
+    // Revocations can't be older than the permission they revoke
+    let activity = agent_activity_since(
+        action.author(),
+        action.prev_action(),
+        oldest_record.action().timestamp(),
+    )?;
+    for a in activity.iter() {
+        let Action::Create(create) = a.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if !matches!(
+            UnitEntryTypes::try_from(ScopedEntryDefIndex {
+                zome_index: app_entry_def.zome_index,
+                zome_type: app_entry_def.entry_index,
+            }),
+            Ok(UnitEntryTypes::PermissionRevocation)
+        ) {
+            continue;
+        }
+        let record = must_get_valid_record(a.action.action_address().clone())?;
+        if let Some(permission_revocation) = permission_revocation_from_record(&record)? {
+            if lineage.contains(&permission_revocation.permission) {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+/// Gets the first ContributorPermission of the renewal chain that the permission belongs to, by
+/// following what each permission renews
+pub fn renewal_chain_root(permission_record: &Record) -> ExternResult<Record> {
+    let mut root_record = permission_record.clone();
+    while let Some(EntryTypes::ContributorPermission(ContributorPermission {
+        renews: Some(renewed_permission_hash),
+        ..
+    })) = app_entry_from_record(&root_record)?
+    {
+        root_record = must_get_valid_record(renewed_permission_hash)?;
+    }
+    Ok(root_record)
+}
+/// Validates that a tool scope is not empty and only contains the Create actions of Tools of
+/// the developer collective
+pub fn validate_tool_scope(
+    for_collective: &ActionHash,
+    tools: &Option<Vec<ActionHash>>,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(tools) = tools {
+        if tools.is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The tool scope of a contributor permission may not be empty.".into(),
+            ));
+        }
+        for tool_hash in tools {
+            let tool_record = must_get_valid_record(tool_hash.clone())?;
+            let Some(EntryTypes::Tool(tool)) = app_entry_from_record(&tool_record)? else {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "The tool scope of a contributor permission may only contain Tools.".into(),
+                ));
+            };
+            if !matches!(tool_record.action(), Action::Create(_)) {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "The tool scope of a contributor permission must reference the original Create actions of Tools."
+                        .into(),
+                ));
+            }
+            if &tool.developer_collective != for_collective {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "The tool scope of a contributor permission may only contain Tools of the developer collective."
+                        .into(),
+                ));
+            }
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_contributor_permission(
@@ -46,18 +288,22 @@ pub fn validate_update_contributor_permission(
         "Contributor Permissions cannot be updated",
     )))
 }
+/// Rules:
+/// 1. ContributorPermissions are revoked with a PermissionRevocation, which validation can see,
+///    and cannot be deleted
 pub fn validate_delete_contributor_permission(
     _action: Delete,
     _original_action: EntryCreationAction,
     _original_contributor_permission: ContributorPermission,
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(ValidateCallbackResult::Invalid(String::from(
-        "Contributor Permissions cannot be deleted",
+        "ContributorPermissions cannot be deleted, they are revoked with a PermissionRevocation",
     )))
 }
 /// Rules
-/// 1. Only the creator of a DeveloperCollective entry is allowed to create links to ContributorPermission
-///    entries
+/// 1. Only the agent that issued a ContributorPermission, i.e. an owner of the DeveloperCollective
+///    or the contributor that accepted an invitation, is allowed to link it from the
+///    DeveloperCollective
 /// 2. Links from a DeveloperCollective can only point to a ContributorPermission for that same
 ///    DeveloperCollective entry
 /// 3. A link from a DeveloperCollective to a ContributorPermission must contain in its tag the AgentPubKey
@@ -76,14 +322,6 @@ pub fn validate_create_link_developer_collective_to_contributor_permissions(
             )))?;
     let developer_collective_record =
         must_get_valid_record(developer_collective_action_hash.clone())?;
-    if developer_collective_record.action().author() != &action.author {
-        return Ok(
-            ValidateCallbackResult::Invalid(
-                "Only the creator of the developer collective is allowed to create links to contributor permissions."
-                    .into(),
-            ),
-        );
-    }
     let _developer_collective: crate::DeveloperCollective = developer_collective_record
         .entry()
         .to_app_option()
@@ -98,6 +336,14 @@ pub fn validate_create_link_developer_collective_to_contributor_permissions(
                 "No action hash associated with link".to_string()
             )))?;
     let contributor_permission_record = must_get_valid_record(contributor_permission_action_hash)?;
+    if contributor_permission_record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                "Only the agent that issued a contributor permission is allowed to create links from the developer collective to it."
+                    .into(),
+            ),
+        );
+    }
     let contributor_permission: crate::ContributorPermission = contributor_permission_record
         .entry()
         .to_app_option()
@@ -129,20 +375,28 @@ pub fn validate_create_link_developer_collective_to_contributor_permissions(
     }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Rules
+/// 1. Links are kept as long as the developer collective exists, revoked permissions stay
+///    discoverable. Owners can delete them once they have deleted the developer collective.
 pub fn validate_delete_link_developer_collective_to_contributor_permissions(
-    _action: DeleteLink,
+    action: DeleteLink,
     _original_action: CreateLink,
-    _base: AnyLinkableHash,
+    base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
+    if let Some(developer_collective_hash) = base.into_action_hash() {
+        if is_deleted_by_owner(&action, &developer_collective_hash)? {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
     Ok(ValidateCallbackResult::Invalid(String::from(
-        "DeveloperCollectiveToContributorPermissions links cannot be deleted",
+        "DeveloperCollectiveToContributorPermissions links can only be deleted together with the DeveloperCollective",
     )))
 }
 /// Rules
-/// 1. Only the creator of a DeveloperCollective entry is allowed to create links to ContributorPermission
-///    entries
+/// 1. Only the agent that issued a ContributorPermission is allowed to create links to it from the
+///    contributor
 /// 2. Links from an agent can only point to a ContributorPermission for that same agent
 /// 3. A link from an agent to a ContributorPermission must contain in its tag the ActionHash
 ///    of the DeveloperCollective that the ContributorPermission is for
@@ -162,7 +416,7 @@ pub fn validate_create_link_contributor_to_contributor_permissions(
     if contributor_permission_record.action().author() != &action.author {
         return Ok(
             ValidateCallbackResult::Invalid(
-                "Only the creator of the developer collective is allowed to create links from contributor agents to their permissions."
+                "Only the agent that issued a contributor permission is allowed to create links from the contributor agent to it."
                     .into(),
             ),
         );
@@ -204,7 +458,67 @@ pub fn validate_create_link_contributor_to_contributor_permissions(
     }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Rules
+/// 1. Links are kept as long as the developer collective in the tag exists. Owners can delete
+///    them once they have deleted the developer collective.
 pub fn validate_delete_link_contributor_to_contributor_permissions(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Ok(developer_collective_hash) = ActionHash::from_raw_39(tag.0) {
+        if is_deleted_by_owner(&action, &developer_collective_hash)? {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ContributorToContributorPermissions links can only be deleted together with the DeveloperCollective",
+    )))
+}
+/// Rules
+/// 1. Only the agent that issued the renewal can link it from the renewed ContributorPermission
+/// 2. The link must point from the renewed ContributorPermission to its renewal
+pub fn validate_create_link_contributor_permission_renewals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let previous_permission_action_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let renewal_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let renewal_record = must_get_valid_record(renewal_action_hash)?;
+    if renewal_record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that renewed a contributor permission can link the renewal.".into(),
+        ));
+    }
+    let Some(EntryTypes::ContributorPermission(renewal)) = app_entry_from_record(&renewal_record)?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link target must be a ContributorPermission entry.".into(),
+        ));
+    };
+    if renewal.renews != Some(previous_permission_action_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links to renewals can only be created from the contributor permission they renew."
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_contributor_permission_renewals(
     _action: DeleteLink,
     _original_action: CreateLink,
     _base: AnyLinkableHash,
@@ -212,6 +526,6 @@ pub fn validate_delete_link_contributor_to_contributor_permissions(
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(ValidateCallbackResult::Invalid(String::from(
-        "ContributorToContributorPermissions links cannot be deleted",
+        "ContributorPermissionRenewals links cannot be deleted",
     )))
 }
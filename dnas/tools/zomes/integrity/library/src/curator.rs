@@ -1,23 +1,94 @@
 use hdi::prelude::*;
+
+use crate::tool_source::validate_url;
+use crate::{is_index_base, validate_icon_ref, IconRef};
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Curator {
     pub name: String,
     pub description: String,
-    pub icon: String,
+    pub icon: IconRef,
     pub website: Option<String>,
     pub email: Option<String>,
     pub meta_data: Option<String>,
 }
+
+const NAME_MAX_LENGTH: usize = 50;
+const DESCRIPTION_MAX_LENGTH: usize = 1200;
+const WEBSITE_MAX_LENGTH: usize = 500;
+const EMAIL_MAX_LENGTH: usize = 254;
+const META_DATA_MAX_LENGTH: usize = 5_000_000;
+
+fn validate_curator_fields(curator: &Curator) -> ValidateCallbackResult {
+    if curator.name.trim().is_empty() {
+        return ValidateCallbackResult::Invalid("Name may not be empty.".into());
+    }
+    if curator.name.chars().count() > NAME_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Name may be no longer than {NAME_MAX_LENGTH} characters."
+        ));
+    }
+    if curator.description.chars().count() > DESCRIPTION_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Description may be no longer than {DESCRIPTION_MAX_LENGTH} characters."
+        ));
+    }
+    if let Some(website) = &curator.website {
+        if website.chars().count() > WEBSITE_MAX_LENGTH {
+            return ValidateCallbackResult::Invalid(format!(
+                "Website may be no longer than {WEBSITE_MAX_LENGTH} characters."
+            ));
+        }
+        if let ValidateCallbackResult::Invalid(e) = validate_url(website, "Website") {
+            return ValidateCallbackResult::Invalid(e);
+        }
+    }
+    if let Some(email) = &curator.email {
+        if email.chars().count() > EMAIL_MAX_LENGTH {
+            return ValidateCallbackResult::Invalid(format!(
+                "Email may be no longer than {EMAIL_MAX_LENGTH} characters."
+            ));
+        }
+        if !is_valid_email(email) {
+            return ValidateCallbackResult::Invalid("Email is not a valid email address.".into());
+        }
+    }
+    if let Some(meta_data) = &curator.meta_data {
+        if meta_data.chars().count() > META_DATA_MAX_LENGTH {
+            return ValidateCallbackResult::Invalid(format!(
+                "Metadata information may be no longer than {META_DATA_MAX_LENGTH} characters."
+            ));
+        }
+    }
+    ValidateCallbackResult::Valid
+}
+
+/// Deliberately permissive: exactly one @ with a non-empty local part and a domain
+/// containing at least one dot, no whitespace.
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
 pub fn validate_create_curator(
     _action: EntryCreationAction,
-    _curator: Curator,
+    curator: Curator,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if let ValidateCallbackResult::Invalid(e) = validate_curator_fields(&curator) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    validate_icon_ref(&curator.icon)
 }
 pub fn validate_update_curator(
     action: Update,
-    _curator: Curator,
+    curator: Curator,
     original_action: EntryCreationAction,
     _original_curator: Curator,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -26,7 +97,10 @@ pub fn validate_update_curator(
             "Curator entries can only be updated by the agent that created the entry.".into(),
         ));
     }
-    Ok(ValidateCallbackResult::Valid)
+    if let ValidateCallbackResult::Invalid(e) = validate_curator_fields(&curator) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    validate_icon_ref(&curator.icon)
 }
 pub fn validate_delete_curator(
     action: Delete,
@@ -106,16 +180,10 @@ pub fn validate_create_link_all_curators(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Check that base address is pointing away from the all_curators anchor
-    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
-        wasm_error!(WasmErrorInner::Guest(
-            "Base address is not an entry hash".into()
-        ))
-    })?;
-    let path = Path::from("all_curators");
-    if path.path_entry_hash()? != base_address_entry_hash {
+    // Check that base address is pointing away from the shard of the all_curators anchor
+    if !is_index_base("all_curators", &base_address, &target_address)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "AllCurators link is not pointing away from the all_curators anchor.".into(),
+            "AllCurators link is not pointing away from the all_curators anchor shard of its target.".into(),
         ));
     }
 
@@ -1,4 +1,6 @@
 use hdi::prelude::*;
+
+use crate::is_link_deleted_by_owner;
 pub fn validate_create_link_curator_to_developer_collectives(
     action: CreateLink,
     base_address: AnyLinkableHash,
@@ -43,16 +45,19 @@ pub fn validate_create_link_curator_to_developer_collectives(
         )))?;
     Ok(ValidateCallbackResult::Valid)
 }
+/// Rules:
+/// 1. Links between Curators and DeveloperCollectives can be deleted by the agent that created
+///    the link or an owner of the developer collective
 pub fn validate_delete_link_curator_to_developer_collectives(
     action: DeleteLink,
     original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    if action.author != original_action.author {
+    if action.author != original_action.author && !is_link_deleted_by_owner(&action, target)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "Only the creator of a link from a Curator to a DeveloperCollective can delete that link."
+            "Only the creator of a link between a Curator and a DeveloperCollective or owners of the DeveloperCollective can delete that link."
                 .into(),
         ));
     }
@@ -103,16 +108,19 @@ pub fn validate_create_link_developer_collective_to_curators(
         )))?;
     Ok(ValidateCallbackResult::Valid)
 }
+/// Rules:
+/// 1. Links between Curators and DeveloperCollectives can be deleted by the agent that created
+///    the link or an owner of the developer collective
 pub fn validate_delete_link_developer_collective_to_curators(
     action: DeleteLink,
     original_action: CreateLink,
-    _base: AnyLinkableHash,
+    base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    if action.author != original_action.author {
+    if action.author != original_action.author && !is_link_deleted_by_owner(&action, base)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "Only the creator of a link from a Curator to a DeveloperCollective can delete that link."
+            "Only the creator of a link between a Curator and a DeveloperCollective or owners of the DeveloperCollective can delete that link."
                 .into(),
         ));
     }
@@ -1,4 +1,8 @@
 use hdi::prelude::*;
+
+use crate::{
+    agent_activity_since, is_developer_collective_owner, is_index_base, validate_icon_ref, IconRef,
+};
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct DeveloperCollective {
@@ -6,7 +10,7 @@ pub struct DeveloperCollective {
     pub description: Option<String>,
     pub website: Option<String>,
     pub contact: Option<String>,
-    pub icon: String,
+    pub icon: IconRef,
     pub meta_data: Option<String>,
 }
 
@@ -14,7 +18,6 @@ const NAME_MAX_LENGTH: usize = 50;
 const DESCRIPTION_MAX_LENGTH: usize = 1200;
 const WEBSITE_MAX_LENGTH: usize = 500;
 const CONTACT_MAX_LENGTH: usize = 300;
-const ICON_MAX_LENGTH: usize = 1_000_000;
 const META_DATA_MAX_LENGTH: usize = 5_000_000;
 
 pub fn validate_create_developer_collective(
@@ -26,10 +29,8 @@ pub fn validate_create_developer_collective(
             "Name may not be longer than {NAME_MAX_LENGTH} characters."
         )));
     }
-    if developer_collective.icon.chars().count() > ICON_MAX_LENGTH {
-        return Ok(ValidateCallbackResult::Invalid(format!(
-            "Icon is too large. Must be smaller than 500KB."
-        )));
+    if let ValidateCallbackResult::Invalid(e) = validate_icon_ref(&developer_collective.icon)? {
+        return Ok(ValidateCallbackResult::Invalid(e));
     }
     if let Some(description) = developer_collective.description {
         if description.chars().count() > DESCRIPTION_MAX_LENGTH {
@@ -64,13 +65,18 @@ pub fn validate_create_developer_collective(
 pub fn validate_update_developer_collective(
     action: Update,
     developer_collective: DeveloperCollective,
-    original_action: EntryCreationAction,
+    _original_action: EntryCreationAction,
     _original_developer_collective: DeveloperCollective,
 ) -> ExternResult<ValidateCallbackResult> {
-    if &action.author != original_action.author() {
+    let original_developer_collective_hash =
+        original_action_hash(action.original_action_address.clone())?;
+    if !is_developer_collective_owner(
+        &action.author,
+        &original_developer_collective_hash,
+        &action.prev_action,
+    )? {
         return Ok(ValidateCallbackResult::Invalid(
-            "Developer collective entry can only be updated by the creator of the collective."
-                .into(),
+            "Developer collective entry can only be updated by an owner of the collective.".into(),
         ));
     }
     if developer_collective.name.chars().count() > NAME_MAX_LENGTH {
@@ -78,10 +84,8 @@ pub fn validate_update_developer_collective(
             "Name may be no longer than {NAME_MAX_LENGTH} characters."
         )));
     }
-    if developer_collective.icon.chars().count() > ICON_MAX_LENGTH {
-        return Ok(ValidateCallbackResult::Invalid(format!(
-            "Icon is too large. Must be smaller than 500KB."
-        )));
+    if let ValidateCallbackResult::Invalid(e) = validate_icon_ref(&developer_collective.icon)? {
+        return Ok(ValidateCallbackResult::Invalid(e));
     }
     if let Some(description) = developer_collective.description {
         if description.chars().count() > DESCRIPTION_MAX_LENGTH {
@@ -113,15 +117,17 @@ pub fn validate_update_developer_collective(
     }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Rules:
+/// 1. A developer collective can only be deleted by its owners
 pub fn validate_delete_developer_collective(
     action: Delete,
-    original_action: EntryCreationAction,
+    _original_action: EntryCreationAction,
     _original_developer_collective: DeveloperCollective,
 ) -> ExternResult<ValidateCallbackResult> {
-    if &action.author != original_action.author() {
+    if !is_developer_collective_owner(&action.author, &action.deletes_address, &action.prev_action)?
+    {
         return Ok(ValidateCallbackResult::Invalid(
-            "Developer collective entry can only be deleted by the creator of the collective."
-                .into(),
+            "Developer collective entry can only be deleted by owners of the collective.".into(),
         ));
     }
     Ok(ValidateCallbackResult::Valid)
@@ -138,11 +144,12 @@ pub fn validate_create_link_developer_collective_updates(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link".to_string()
             )))?;
-    let collective_record = must_get_valid_record(collective_action_hash)?;
-    if collective_record.action().author() != &action.author {
+    let collective_record = must_get_valid_record(collective_action_hash.clone())?;
+    if !is_developer_collective_owner(&action.author, &collective_action_hash, &action.prev_action)?
+    {
         return Ok(
             ValidateCallbackResult::Invalid(
-                "Links to DeveloperCollective entry updates can only be created by the agent that created the DeveloperCollective entry."
+                "Links to DeveloperCollective entry updates can only be created by an owner of the DeveloperCollective."
                     .into(),
             ),
         );
@@ -192,17 +199,10 @@ pub fn validate_create_link_all_developer_collectives(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Check that base address is pointing away from the all_developer_collectives anchor
-    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
-        wasm_error!(WasmErrorInner::Guest(
-            "Base address is not an entry hash".into()
-        ))
-    })?;
-    let path = Path::from("all_developer_collectives");
-    if path.path_entry_hash()? != base_address_entry_hash {
+    // Check that base address is pointing away from the shard of the all_developer_collectives anchor
+    if !is_index_base("all_developer_collectives", &base_address, &target_address)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "AllDeveloperCollectives link is not pointing away from the all_developer_collectives anchor."
-                .into(),
+            "AllDeveloperCollectives link is not pointing away from the all_developer_collectives anchor shard of its target.".into(),
         ));
     }
 
@@ -232,18 +232,85 @@ pub fn validate_create_link_all_developer_collectives(
 }
 /// Rules
 /// 1. Only the agent that created the link (and therefore created the DeveloperCollective entry according
-///    to the rules when creating those links) can delete the link
+///    to the rules when creating those links) or an owner of the DeveloperCollective can delete the link
 pub fn validate_delete_link_all_developer_collectives(
     action: DeleteLink,
     original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    if action.author != original_action.author {
+    if action.author != original_action.author && !is_link_deleted_by_owner(&action, target)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "Links from the all_developer_collectives anchor to a DeveloperCollective entry can only be deleted by the agent that created the link and therefore the DeveloperCollective entry.".into(),
+            "Links from the all_developer_collectives anchor to a DeveloperCollective entry can only be deleted by the agent that created the link or owners of the DeveloperCollective.".into(),
         ));
     }
     Ok(ValidateCallbackResult::Valid)
 }
+
+/// Whether the author of the DeleteLink is an owner of the developer collective the link
+/// refers to. Used for the index links that owners clean up when deleting a collective.
+pub fn is_link_deleted_by_owner(
+    action: &DeleteLink,
+    developer_collective_address: AnyLinkableHash,
+) -> ExternResult<bool> {
+    let Some(developer_collective_hash) = developer_collective_address.into_action_hash() else {
+        return Ok(false);
+    };
+    is_developer_collective_owner(
+        &action.author,
+        &developer_collective_hash,
+        &action.prev_action,
+    )
+}
+
+/// Whether the agent has deleted the developer collective on its own source chain as of
+/// `chain_top`. Links that are kept for auditing while the collective exists may be cleaned up
+/// by its owners once they have deleted it.
+pub fn has_deleted_developer_collective(
+    agent: &AgentPubKey,
+    developer_collective_hash: &ActionHash,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    let developer_collective_record = must_get_valid_record(developer_collective_hash.clone())?;
+    // Only owners can delete the collective, and they only become owners after it was created
+    let activity = agent_activity_since(
+        agent,
+        chain_top,
+        developer_collective_record.action().timestamp(),
+    )?;
+    Ok(activity.iter().any(|a| {
+        matches!(
+            a.action.action(),
+            Action::Delete(delete) if &delete.deletes_address == developer_collective_hash
+        )
+    }))
+}
+
+/// Whether the author of the DeleteLink is an owner that has already deleted the developer
+/// collective
+pub fn is_deleted_by_owner(
+    action: &DeleteLink,
+    developer_collective_hash: &ActionHash,
+) -> ExternResult<bool> {
+    Ok(is_developer_collective_owner(
+        &action.author,
+        developer_collective_hash,
+        &action.prev_action,
+    )? && has_deleted_developer_collective(
+        &action.author,
+        developer_collective_hash,
+        &action.prev_action,
+    )?)
+}
+
+/// Follows the chain of updates back to the action hash of the Create action of an entry
+pub fn original_action_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    let mut action_hash = action_hash;
+    loop {
+        match must_get_action(action_hash.clone())?.action() {
+            Action::Update(update) => action_hash = update.original_action_address.clone(),
+            _ => return Ok(action_hash),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use hdi::prelude::*;
+
+use crate::{validate_digest, HashAlgorithm};
+
+/// A piece of a file stored on the DHT. Chunks are content addressed, so identical chunks are
+/// only stored once, even if they are part of several files.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct FileChunk {
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+}
+
+/// A file stored on the DHT, e.g. the .webhapp of a Tool release. The file is split into
+/// chunks of FILE_CHUNK_SIZE bytes, only the last chunk may be smaller.
+///
+/// The chunks are fetched when the manifest is validated, so they have to be stored before
+/// it. Validation only checks their sizes: the manifest carries the Sha256 digest of the whole
+/// file, which Tools that use it as their source must declare in their hashes, and downloaders
+/// verify the file against that digest.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct FileManifest {
+    pub name: String,   // File name, e.g. my-tool.webhapp
+    pub size: u64,      // Size of the whole file in bytes
+    pub sha256: String, // Lowercase hex Sha256 digest of the whole file
+    pub chunks: Vec<EntryHash>,
+}
+
+pub const FILE_CHUNK_SIZE: usize = 1_000_000; // bytes
+pub const FILE_MAX_SIZE: u64 = 100_000_000; // bytes
+const FILE_NAME_MAX_LENGTH: usize = 250;
+
+/// Rules:
+/// 1. A chunk may not be empty or larger than FILE_CHUNK_SIZE
+pub fn validate_create_file_chunk(
+    _action: EntryCreationAction,
+    file_chunk: FileChunk,
+) -> ExternResult<ValidateCallbackResult> {
+    if file_chunk.data.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "File chunks may not be empty.".into(),
+        ));
+    }
+    if file_chunk.data.len() > FILE_CHUNK_SIZE {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "File chunks may be no larger than {FILE_CHUNK_SIZE} bytes."
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_file_chunk(
+    _action: Update,
+    _file_chunk: FileChunk,
+    _original_action: EntryCreationAction,
+    _original_file_chunk: FileChunk,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "File chunks cannot be updated",
+    )))
+}
+/// Rules:
+/// 1. Chunks are shared by every file that contains them and can therefore not be deleted
+pub fn validate_delete_file_chunk(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_file_chunk: FileChunk,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "File chunks cannot be deleted",
+    )))
+}
+
+/// Rules:
+/// 1. The name must be a non-empty file name of bounded length
+/// 2. The file may not be empty or larger than FILE_MAX_SIZE
+/// 3. The number of chunks must match the size of the file
+/// 4. The Sha256 digest of the file must be well-formed
+/// 5. Every chunk must be a FileChunk of FILE_CHUNK_SIZE bytes, except for the last one, and
+///    the sizes of the chunks must add up to the size of the file
+pub fn validate_create_file_manifest(
+    _action: EntryCreationAction,
+    file_manifest: FileManifest,
+) -> ExternResult<ValidateCallbackResult> {
+    if file_manifest.name.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "File name may not be empty.".into(),
+        ));
+    }
+    if file_manifest.name.chars().count() > FILE_NAME_MAX_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "File name may be no longer than {FILE_NAME_MAX_LENGTH} characters."
+        )));
+    }
+    if file_manifest.name.contains(['/', '\\']) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "File name may not contain path separators.".into(),
+        ));
+    }
+    if file_manifest.size == 0 || file_manifest.size > FILE_MAX_SIZE {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Files must be between 1 and {FILE_MAX_SIZE} bytes large."
+        )));
+    }
+    let expected_chunks = file_manifest.size.div_ceil(FILE_CHUNK_SIZE as u64) as usize;
+    if file_manifest.chunks.len() != expected_chunks {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "A file of {} bytes must consist of {expected_chunks} chunks.",
+            file_manifest.size
+        )));
+    }
+    if let ValidateCallbackResult::Invalid(e) =
+        validate_digest(&HashAlgorithm::Sha256, &file_manifest.sha256)
+    {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    // At most FILE_MAX_SIZE / FILE_CHUNK_SIZE chunks, as checked above
+    let mut size = 0;
+    for (i, chunk_hash) in file_manifest.chunks.iter().enumerate() {
+        let entry = must_get_entry(chunk_hash.clone())?;
+        let Ok(file_chunk) = FileChunk::try_from(entry.content) else {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The chunks of a file must be FileChunk entries.".into(),
+            ));
+        };
+        if i + 1 < file_manifest.chunks.len() && file_chunk.data.len() != FILE_CHUNK_SIZE {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Every chunk of a file but the last must be exactly {FILE_CHUNK_SIZE} bytes large."
+            )));
+        }
+        size += file_chunk.data.len() as u64;
+    }
+    if size != file_manifest.size {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The chunks of a file must add up to its size.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_file_manifest(
+    _action: Update,
+    _file_manifest: FileManifest,
+    _original_action: EntryCreationAction,
+    _original_file_manifest: FileManifest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "File manifests cannot be updated",
+    )))
+}
+/// Rules:
+/// 1. Tool releases may refer to a file manifest, so they cannot be deleted
+pub fn validate_delete_file_manifest(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_file_manifest: FileManifest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "File manifests cannot be deleted",
+    )))
+}
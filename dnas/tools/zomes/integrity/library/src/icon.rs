@@ -0,0 +1,98 @@
+use hdi::prelude::*;
+
+/// An image that Tools, Curators and DeveloperCollectives refer to by its EntryHash. Identical
+/// images have the same EntryHash and are therefore only stored once, no matter how many
+/// entries or revisions refer to them.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Icon {
+    pub mime_type: String,
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+}
+
+/// Reference to the icon of a Tool, Curator or DeveloperCollective. Entries published before
+/// icons were stored as separate entries have the image inline (as a data URL for Tools) and
+/// still deserialize into the `Legacy` variant, but new entries and updates must refer to an
+/// Icon entry.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum IconRef {
+    Hash(EntryHash),
+    Legacy(String),
+}
+
+pub const ICON_MAX_SIZE: usize = 750_000; // bytes
+pub const ICON_MIME_TYPES: [&str; 5] = [
+    "image/png",
+    "image/jpeg",
+    "image/svg+xml",
+    "image/webp",
+    "image/gif",
+];
+
+fn validate_icon(icon: &Icon) -> ValidateCallbackResult {
+    if !ICON_MIME_TYPES.contains(&icon.mime_type.as_str()) {
+        return ValidateCallbackResult::Invalid(format!(
+            "Icon must be of one of the following types: {}.",
+            ICON_MIME_TYPES.join(", ")
+        ));
+    }
+    if icon.data.is_empty() {
+        return ValidateCallbackResult::Invalid("Icon may not be empty.".into());
+    }
+    if icon.data.len() > ICON_MAX_SIZE {
+        return ValidateCallbackResult::Invalid(format!(
+            "Icon is too large. It may be no larger than {ICON_MAX_SIZE} bytes."
+        ));
+    }
+    ValidateCallbackResult::Valid
+}
+
+/// The icon of a new entry or revision must refer to a valid Icon entry
+pub fn validate_icon_ref(icon: &IconRef) -> ExternResult<ValidateCallbackResult> {
+    let IconRef::Hash(icon_hash) = icon else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Icon must be the EntryHash of an Icon entry.".into(),
+        ));
+    };
+    let entry = must_get_entry(icon_hash.clone())?;
+    let Ok(icon) = Icon::try_from(entry.content) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The referenced icon is not an Icon entry.".into(),
+        ));
+    };
+    Ok(validate_icon(&icon))
+}
+
+/// Rules:
+/// 1. The mime type must be one of the supported image types
+/// 2. The image may not be empty or larger than ICON_MAX_SIZE
+pub fn validate_create_icon(
+    _action: EntryCreationAction,
+    icon: Icon,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(validate_icon(&icon))
+}
+pub fn validate_update_icon(
+    _action: Update,
+    _icon: Icon,
+    _original_action: EntryCreationAction,
+    _original_icon: Icon,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Icons cannot be updated",
+    )))
+}
+/// Rules:
+/// 1. Icons are shared by every entry that refers to the same image and can therefore not be
+///    deleted
+pub fn validate_delete_icon(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_icon: Icon,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Icons cannot be deleted",
+    )))
+}
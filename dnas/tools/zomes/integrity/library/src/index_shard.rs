@@ -0,0 +1,34 @@
+use hdi::prelude::*;
+
+/// Number of shards that the links of a global index anchor are spread over
+pub const INDEX_SHARD_COUNT: u8 = 16;
+
+/// Gets the shard of a global index anchor, e.g. `all_tools.a`, that a link to the target is
+/// created from. The shard is derived from the first byte of the target hash, so that the links
+/// of an index are spread over the DHT instead of piling up at a single authority.
+pub fn index_shard_path(anchor: &str, target: &AnyLinkableHash) -> Path {
+    let shard = target.get_raw_32()[0] % INDEX_SHARD_COUNT;
+    Path::from(format!("{anchor}.{shard:x}"))
+}
+
+/// Gets all paths that links of a global index anchor can be found at: every shard plus the
+/// unsharded anchor that links were created from before sharding
+pub fn index_paths(anchor: &str) -> Vec<Path> {
+    std::iter::once(Path::from(anchor))
+        .chain((0..INDEX_SHARD_COUNT).map(|shard| Path::from(format!("{anchor}.{shard:x}"))))
+        .collect()
+}
+
+/// Whether the base of a link to the target is the shard of the global index anchor that
+/// belongs to the target. New links may not be created from the unsharded anchor; the links
+/// created from it before sharding remain readable through `index_paths`.
+pub fn is_index_base(
+    anchor: &str,
+    base_address: &AnyLinkableHash,
+    target_address: &AnyLinkableHash,
+) -> ExternResult<bool> {
+    let Some(base_address_entry_hash) = base_address.clone().into_entry_hash() else {
+        return Ok(false);
+    };
+    Ok(index_shard_path(anchor, target_address).path_entry_hash()? == base_address_entry_hash)
+}
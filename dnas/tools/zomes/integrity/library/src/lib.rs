@@ -6,12 +6,36 @@ pub mod owner_to_developer_collective;
 pub use owner_to_developer_collective::*;
 pub mod tool;
 pub use tool::*;
+pub mod tool_source;
+pub use tool_source::*;
+pub mod tool_hashes;
+pub use tool_hashes::*;
+pub mod tool_tags;
+pub use tool_tags::*;
+pub mod icon;
+pub use icon::*;
+pub mod file_storage;
+pub use file_storage::*;
+pub mod release_channel;
+pub use release_channel::*;
 pub mod contributor_permission;
 pub use contributor_permission::*;
+pub mod permission_revocation;
+pub use permission_revocation::*;
+pub mod contributor_invitation;
+pub use contributor_invitation::*;
+pub mod co_ownership;
+pub use co_ownership::*;
+pub mod ownership_transfer;
+pub use ownership_transfer::*;
 pub mod developer_collective;
 pub use developer_collective::*;
 pub mod curator;
 pub use curator::*;
+pub mod index_shard;
+pub use index_shard::*;
+pub mod search;
+pub use search::*;
 pub mod all_agents;
 pub use all_agents::*;
 use hdi::prelude::*;
@@ -19,11 +43,19 @@ use hdi::prelude::*;
 #[serde(tag = "type")]
 #[hdk_entry_types]
 #[unit_enum(UnitEntryTypes)]
+#[allow(clippy::large_enum_variant)]
 pub enum EntryTypes {
     Curator(Curator),
     DeveloperCollective(DeveloperCollective),
     ContributorPermission(ContributorPermission),
     Tool(Tool),
+    CoOwnership(CoOwnership),
+    OwnershipTransfer(OwnershipTransfer),
+    ContributorInvitation(ContributorInvitation),
+    Icon(Icon),
+    FileChunk(FileChunk),
+    FileManifest(FileManifest),
+    PermissionRevocation(PermissionRevocation),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
@@ -42,6 +74,18 @@ pub enum LinkTypes {
     AllAgents,
     AllCurators,
     AllDeveloperCollectives,
+    DeveloperCollectiveToCoOwners,
+    DeveloperCollectiveToOwnershipTransfers,
+    RecipientToOwnershipTransfers,
+    DeveloperCollectiveToContributorInvitations,
+    InviteeToContributorInvitations,
+    ContributorPermissionRenewals,
+    AllTools,
+    SearchTermToTools,
+    TagToTools,
+    AllTags,
+    PermissionRevocations,
+    RevocationAcknowledgements,
 }
 #[hdk_extern]
 pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
@@ -73,9 +117,40 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         contributor_permission,
                     )
                 }
+                EntryTypes::CoOwnership(co_ownership) => {
+                    validate_create_co_ownership(EntryCreationAction::Create(action), co_ownership)
+                }
+                EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                    validate_create_ownership_transfer(
+                        EntryCreationAction::Create(action),
+                        ownership_transfer,
+                    )
+                }
+                EntryTypes::ContributorInvitation(contributor_invitation) => {
+                    validate_create_contributor_invitation(
+                        EntryCreationAction::Create(action),
+                        contributor_invitation,
+                    )
+                }
+                EntryTypes::PermissionRevocation(permission_revocation) => {
+                    validate_create_permission_revocation(
+                        EntryCreationAction::Create(action),
+                        permission_revocation,
+                    )
+                }
                 EntryTypes::Tool(tool) => {
                     validate_create_tool(EntryCreationAction::Create(action), tool)
                 }
+                EntryTypes::Icon(icon) => {
+                    validate_create_icon(EntryCreationAction::Create(action), icon)
+                }
+                EntryTypes::FileChunk(file_chunk) => {
+                    validate_create_file_chunk(EntryCreationAction::Create(action), file_chunk)
+                }
+                EntryTypes::FileManifest(file_manifest) => validate_create_file_manifest(
+                    EntryCreationAction::Create(action),
+                    file_manifest,
+                ),
             },
             OpEntry::UpdateEntry {
                 app_entry, action, ..
@@ -95,9 +170,40 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         contributor_permission,
                     )
                 }
+                EntryTypes::CoOwnership(co_ownership) => {
+                    validate_create_co_ownership(EntryCreationAction::Update(action), co_ownership)
+                }
+                EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                    validate_create_ownership_transfer(
+                        EntryCreationAction::Update(action),
+                        ownership_transfer,
+                    )
+                }
+                EntryTypes::ContributorInvitation(contributor_invitation) => {
+                    validate_create_contributor_invitation(
+                        EntryCreationAction::Update(action),
+                        contributor_invitation,
+                    )
+                }
+                EntryTypes::PermissionRevocation(permission_revocation) => {
+                    validate_create_permission_revocation(
+                        EntryCreationAction::Update(action),
+                        permission_revocation,
+                    )
+                }
                 EntryTypes::Tool(tool) => {
                     validate_create_tool(EntryCreationAction::Update(action), tool)
                 }
+                EntryTypes::Icon(icon) => {
+                    validate_create_icon(EntryCreationAction::Update(action), icon)
+                }
+                EntryTypes::FileChunk(file_chunk) => {
+                    validate_create_file_chunk(EntryCreationAction::Update(action), file_chunk)
+                }
+                EntryTypes::FileManifest(file_manifest) => validate_create_file_manifest(
+                    EntryCreationAction::Update(action),
+                    file_manifest,
+                ),
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -115,6 +221,82 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     }
                 };
                 match app_entry {
+                    EntryTypes::CoOwnership(co_ownership) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_co_ownership = match CoOwnership::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get CoOwnership from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_co_ownership(
+                            action,
+                            co_ownership,
+                            original_create_action,
+                            original_co_ownership,
+                        )
+                    }
+                    EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_ownership_transfer =
+                            match OwnershipTransfer::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get OwnershipTransfer from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_ownership_transfer(
+                            action,
+                            ownership_transfer,
+                            original_create_action,
+                            original_ownership_transfer,
+                        )
+                    }
+                    EntryTypes::ContributorInvitation(contributor_invitation) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_contributor_invitation =
+                            match ContributorInvitation::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ContributorInvitation from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_contributor_invitation(
+                            action,
+                            contributor_invitation,
+                            original_create_action,
+                            original_contributor_invitation,
+                        )
+                    }
+                    EntryTypes::PermissionRevocation(permission_revocation) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_permission_revocation =
+                            match PermissionRevocation::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get PermissionRevocation from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_permission_revocation(
+                            action,
+                            permission_revocation,
+                            original_create_action,
+                            original_permission_revocation,
+                        )
+                    }
                     EntryTypes::Tool(tool) => {
                         let original_app_entry =
                             must_get_valid_record(action.clone().original_action_address)?;
@@ -128,6 +310,56 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         };
                         validate_update_tool(action, tool, original_create_action, original_tool)
                     }
+                    EntryTypes::Icon(icon) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_icon = match Icon::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Icon from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_icon(action, icon, original_create_action, original_icon)
+                    }
+                    EntryTypes::FileChunk(file_chunk) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_file_chunk = match FileChunk::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get FileChunk from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_file_chunk(
+                            action,
+                            file_chunk,
+                            original_create_action,
+                            original_file_chunk,
+                        )
+                    }
+                    EntryTypes::FileManifest(file_manifest) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_file_manifest =
+                            match FileManifest::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get FileManifest from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_file_manifest(
+                            action,
+                            file_manifest,
+                            original_create_action,
+                            original_file_manifest,
+                        )
+                    }
                     EntryTypes::ContributorPermission(contributor_permission) => {
                         let original_app_entry =
                             must_get_valid_record(action.clone().original_action_address)?;
@@ -245,9 +477,48 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         contributor_permission,
                     )
                 }
+                EntryTypes::CoOwnership(co_ownership) => validate_delete_co_ownership(
+                    delete_entry.clone().action,
+                    original_action,
+                    co_ownership,
+                ),
+                EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                    validate_delete_ownership_transfer(
+                        delete_entry.clone().action,
+                        original_action,
+                        ownership_transfer,
+                    )
+                }
+                EntryTypes::ContributorInvitation(contributor_invitation) => {
+                    validate_delete_contributor_invitation(
+                        delete_entry.clone().action,
+                        original_action,
+                        contributor_invitation,
+                    )
+                }
+                EntryTypes::PermissionRevocation(permission_revocation) => {
+                    validate_delete_permission_revocation(
+                        delete_entry.clone().action,
+                        original_action,
+                        permission_revocation,
+                    )
+                }
                 EntryTypes::Tool(tool) => {
                     validate_delete_tool(delete_entry.clone().action, original_action, tool)
                 }
+                EntryTypes::Icon(icon) => {
+                    validate_delete_icon(delete_entry.clone().action, original_action, icon)
+                }
+                EntryTypes::FileChunk(file_chunk) => validate_delete_file_chunk(
+                    delete_entry.clone().action,
+                    original_action,
+                    file_chunk,
+                ),
+                EntryTypes::FileManifest(file_manifest) => validate_delete_file_manifest(
+                    delete_entry.clone().action,
+                    original_action,
+                    file_manifest,
+                ),
             }
         }
         FlatOp::RegisterCreateLink {
@@ -331,6 +602,80 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             LinkTypes::AllCurators => {
                 validate_create_link_all_curators(action, base_address, target_address, tag)
             }
+            LinkTypes::DeveloperCollectiveToCoOwners => {
+                validate_create_link_developer_collective_to_co_owners(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::DeveloperCollectiveToOwnershipTransfers => {
+                validate_create_link_developer_collective_to_ownership_transfers(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::RecipientToOwnershipTransfers => {
+                validate_create_link_recipient_to_ownership_transfers(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::DeveloperCollectiveToContributorInvitations => {
+                validate_create_link_developer_collective_to_contributor_invitations(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::InviteeToContributorInvitations => {
+                validate_create_link_invitee_to_contributor_invitations(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::ContributorPermissionRenewals => {
+                validate_create_link_contributor_permission_renewals(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllTools => {
+                validate_create_link_all_tools(action, base_address, target_address, tag)
+            }
+            LinkTypes::SearchTermToTools => {
+                validate_create_link_search_term_to_tools(action, base_address, target_address, tag)
+            }
+            LinkTypes::TagToTools => {
+                validate_create_link_tag_to_tools(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllTags => {
+                validate_create_link_all_tags(action, base_address, target_address, tag)
+            }
+            LinkTypes::PermissionRevocations => validate_create_link_permission_revocations(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::RevocationAcknowledgements => {
+                validate_create_link_revocation_acknowledgements(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
             LinkTypes::AllDeveloperCollectives => validate_create_link_all_developer_collectives(
                 action,
                 base_address,
@@ -451,6 +796,104 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
+            LinkTypes::DeveloperCollectiveToCoOwners => {
+                validate_delete_link_developer_collective_to_co_owners(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::DeveloperCollectiveToOwnershipTransfers => {
+                validate_delete_link_developer_collective_to_ownership_transfers(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::RecipientToOwnershipTransfers => {
+                validate_delete_link_recipient_to_ownership_transfers(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::DeveloperCollectiveToContributorInvitations => {
+                validate_delete_link_developer_collective_to_contributor_invitations(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::InviteeToContributorInvitations => {
+                validate_delete_link_invitee_to_contributor_invitations(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::ContributorPermissionRenewals => {
+                validate_delete_link_contributor_permission_renewals(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllTools => validate_delete_link_all_tools(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::SearchTermToTools => validate_delete_link_search_term_to_tools(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TagToTools => validate_delete_link_tag_to_tools(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllTags => validate_delete_link_all_tags(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PermissionRevocations => validate_delete_link_permission_revocations(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::RevocationAcknowledgements => {
+                validate_delete_link_revocation_acknowledgements(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
             LinkTypes::AllDeveloperCollectives => validate_delete_link_all_developer_collectives(
                 action,
                 original_action,
@@ -476,12 +919,43 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         contributor_permission,
                     )
                 }
-                EntryTypes::Tool(tool) => {
-                    validate_create_tool(EntryCreationAction::Create(action), tool)
+                EntryTypes::CoOwnership(co_ownership) => {
+                    validate_create_co_ownership(EntryCreationAction::Create(action), co_ownership)
                 }
-            },
-            OpRecord::UpdateEntry {
-                original_action_hash,
+                EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                    validate_create_ownership_transfer(
+                        EntryCreationAction::Create(action),
+                        ownership_transfer,
+                    )
+                }
+                EntryTypes::ContributorInvitation(contributor_invitation) => {
+                    validate_create_contributor_invitation(
+                        EntryCreationAction::Create(action),
+                        contributor_invitation,
+                    )
+                }
+                EntryTypes::PermissionRevocation(permission_revocation) => {
+                    validate_create_permission_revocation(
+                        EntryCreationAction::Create(action),
+                        permission_revocation,
+                    )
+                }
+                EntryTypes::Tool(tool) => {
+                    validate_create_tool(EntryCreationAction::Create(action), tool)
+                }
+                EntryTypes::Icon(icon) => {
+                    validate_create_icon(EntryCreationAction::Create(action), icon)
+                }
+                EntryTypes::FileChunk(file_chunk) => {
+                    validate_create_file_chunk(EntryCreationAction::Create(action), file_chunk)
+                }
+                EntryTypes::FileManifest(file_manifest) => validate_create_file_manifest(
+                    EntryCreationAction::Create(action),
+                    file_manifest,
+                ),
+            },
+            OpRecord::UpdateEntry {
+                original_action_hash,
                 app_entry,
                 action,
                 ..
@@ -596,6 +1070,127 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             Ok(result)
                         }
                     }
+                    EntryTypes::CoOwnership(co_ownership) => {
+                        let result = validate_create_co_ownership(
+                            EntryCreationAction::Update(action.clone()),
+                            co_ownership.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_co_ownership: Option<CoOwnership> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_co_ownership = match original_co_ownership {
+                                Some(co_ownership) => co_ownership,
+                                None => {
+                                    return Ok(ValidateCallbackResult::Invalid(
+                                        "The updated entry type must be the same as the original entry type"
+                                            .to_string(),
+                                    ));
+                                }
+                            };
+                            validate_update_co_ownership(
+                                action,
+                                co_ownership,
+                                original_action,
+                                original_co_ownership,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                        let result = validate_create_ownership_transfer(
+                            EntryCreationAction::Update(action.clone()),
+                            ownership_transfer.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_ownership_transfer: Option<OwnershipTransfer> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_ownership_transfer = match original_ownership_transfer {
+                                Some(ownership_transfer) => ownership_transfer,
+                                None => {
+                                    return Ok(ValidateCallbackResult::Invalid(
+                                        "The updated entry type must be the same as the original entry type"
+                                            .to_string(),
+                                    ));
+                                }
+                            };
+                            validate_update_ownership_transfer(
+                                action,
+                                ownership_transfer,
+                                original_action,
+                                original_ownership_transfer,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::ContributorInvitation(contributor_invitation) => {
+                        let result = validate_create_contributor_invitation(
+                            EntryCreationAction::Update(action.clone()),
+                            contributor_invitation.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_contributor_invitation: Option<ContributorInvitation> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_contributor_invitation =
+                                match original_contributor_invitation {
+                                    Some(contributor_invitation) => contributor_invitation,
+                                    None => {
+                                        return Ok(ValidateCallbackResult::Invalid(
+                                        "The updated entry type must be the same as the original entry type"
+                                            .to_string(),
+                                    ));
+                                    }
+                                };
+                            validate_update_contributor_invitation(
+                                action,
+                                contributor_invitation,
+                                original_action,
+                                original_contributor_invitation,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::PermissionRevocation(permission_revocation) => {
+                        let result = validate_create_permission_revocation(
+                            EntryCreationAction::Update(action.clone()),
+                            permission_revocation.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_permission_revocation: Option<PermissionRevocation> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_permission_revocation =
+                                match original_permission_revocation {
+                                    Some(permission_revocation) => permission_revocation,
+                                    None => {
+                                        return Ok(ValidateCallbackResult::Invalid(
+                                        "The updated entry type must be the same as the original entry type"
+                                            .to_string(),
+                                    ));
+                                    }
+                                };
+                            validate_update_permission_revocation(
+                                action,
+                                permission_revocation,
+                                original_action,
+                                original_permission_revocation,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
                     EntryTypes::Tool(tool) => {
                         let result = validate_create_tool(
                             EntryCreationAction::Update(action.clone()),
@@ -622,6 +1217,94 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             Ok(result)
                         }
                     }
+                    EntryTypes::Icon(icon) => {
+                        let result = validate_create_icon(
+                            EntryCreationAction::Update(action.clone()),
+                            icon.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_icon: Option<Icon> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_icon = match original_icon {
+                                Some(icon) => icon,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_icon(action, icon, original_action, original_icon)
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::FileChunk(file_chunk) => {
+                        let result = validate_create_file_chunk(
+                            EntryCreationAction::Update(action.clone()),
+                            file_chunk.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_file_chunk: Option<FileChunk> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_file_chunk = match original_file_chunk {
+                                Some(file_chunk) => file_chunk,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_file_chunk(
+                                action,
+                                file_chunk,
+                                original_action,
+                                original_file_chunk,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::FileManifest(file_manifest) => {
+                        let result = validate_create_file_manifest(
+                            EntryCreationAction::Update(action.clone()),
+                            file_manifest.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_file_manifest: Option<FileManifest> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_file_manifest = match original_file_manifest {
+                                Some(file_manifest) => file_manifest,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_file_manifest(
+                                action,
+                                file_manifest,
+                                original_action,
+                                original_file_manifest,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
                 }
             }
             OpRecord::DeleteEntry {
@@ -695,9 +1378,46 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             original_contributor_permission,
                         )
                     }
+                    EntryTypes::CoOwnership(original_co_ownership) => {
+                        validate_delete_co_ownership(action, original_action, original_co_ownership)
+                    }
+                    EntryTypes::OwnershipTransfer(original_ownership_transfer) => {
+                        validate_delete_ownership_transfer(
+                            action,
+                            original_action,
+                            original_ownership_transfer,
+                        )
+                    }
+                    EntryTypes::ContributorInvitation(original_contributor_invitation) => {
+                        validate_delete_contributor_invitation(
+                            action,
+                            original_action,
+                            original_contributor_invitation,
+                        )
+                    }
+                    EntryTypes::PermissionRevocation(original_permission_revocation) => {
+                        validate_delete_permission_revocation(
+                            action,
+                            original_action,
+                            original_permission_revocation,
+                        )
+                    }
                     EntryTypes::Tool(original_tool) => {
                         validate_delete_tool(action, original_action, original_tool)
                     }
+                    EntryTypes::Icon(original_icon) => {
+                        validate_delete_icon(action, original_action, original_icon)
+                    }
+                    EntryTypes::FileChunk(original_file_chunk) => {
+                        validate_delete_file_chunk(action, original_action, original_file_chunk)
+                    }
+                    EntryTypes::FileManifest(original_file_manifest) => {
+                        validate_delete_file_manifest(
+                            action,
+                            original_action,
+                            original_file_manifest,
+                        )
+                    }
                 }
             }
             OpRecord::CreateLink {
@@ -781,6 +1501,83 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 LinkTypes::AllCurators => {
                     validate_create_link_all_curators(action, base_address, target_address, tag)
                 }
+                LinkTypes::DeveloperCollectiveToCoOwners => {
+                    validate_create_link_developer_collective_to_co_owners(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DeveloperCollectiveToOwnershipTransfers => {
+                    validate_create_link_developer_collective_to_ownership_transfers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RecipientToOwnershipTransfers => {
+                    validate_create_link_recipient_to_ownership_transfers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DeveloperCollectiveToContributorInvitations => {
+                    validate_create_link_developer_collective_to_contributor_invitations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteeToContributorInvitations => {
+                    validate_create_link_invitee_to_contributor_invitations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ContributorPermissionRenewals => {
+                    validate_create_link_contributor_permission_renewals(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllTools => {
+                    validate_create_link_all_tools(action, base_address, target_address, tag)
+                }
+                LinkTypes::SearchTermToTools => validate_create_link_search_term_to_tools(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::TagToTools => {
+                    validate_create_link_tag_to_tools(action, base_address, target_address, tag)
+                }
+                LinkTypes::AllTags => {
+                    validate_create_link_all_tags(action, base_address, target_address, tag)
+                }
+                LinkTypes::PermissionRevocations => validate_create_link_permission_revocations(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::RevocationAcknowledgements => {
+                    validate_create_link_revocation_acknowledgements(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
                 LinkTypes::AllDeveloperCollectives => {
                     validate_create_link_all_developer_collectives(
                         action,
@@ -917,6 +1714,106 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         create_link.target_address,
                         create_link.tag,
                     ),
+                    LinkTypes::DeveloperCollectiveToCoOwners => {
+                        validate_delete_link_developer_collective_to_co_owners(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::DeveloperCollectiveToOwnershipTransfers => {
+                        validate_delete_link_developer_collective_to_ownership_transfers(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::RecipientToOwnershipTransfers => {
+                        validate_delete_link_recipient_to_ownership_transfers(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::DeveloperCollectiveToContributorInvitations => {
+                        validate_delete_link_developer_collective_to_contributor_invitations(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::InviteeToContributorInvitations => {
+                        validate_delete_link_invitee_to_contributor_invitations(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::ContributorPermissionRenewals => {
+                        validate_delete_link_contributor_permission_renewals(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AllTools => validate_delete_link_all_tools(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::SearchTermToTools => validate_delete_link_search_term_to_tools(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::TagToTools => validate_delete_link_tag_to_tools(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllTags => validate_delete_link_all_tags(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::PermissionRevocations => {
+                        validate_delete_link_permission_revocations(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::RevocationAcknowledgements => {
+                        validate_delete_link_revocation_acknowledgements(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
                     LinkTypes::AllDeveloperCollectives => {
                         validate_delete_link_all_developer_collectives(
                             action,
@@ -1,12 +1,24 @@
 use hdi::prelude::*;
+
+use crate::{
+    has_responded_to_ownership_transfer, is_deleted_by_owner, owner_grant_from_record,
+    ownership_transfer_from_record,
+};
 /// Rules:
 ///
-/// 1. Only the creator (owner) of the developer collective can create a link to their public key
+/// 1. Owners can only create a link from their own public key
+/// 2. The link may not be older than the developer collective
+/// 3. The agent must be the creator of the developer collective, or the tag must contain the
+///    action hash of a CoOwnership or OwnershipTransfer of the developer collective for that agent,
+///    or of the Delete with which the recipient declined an OwnershipTransfer the agent offered
+/// 4. The link may not be older than the record in its tag
+/// 5. An OwnershipTransfer can only be accepted if the recipient has not accepted or declined it
+///    before
 pub fn validate_create_link_owner_to_developer_collective(
     action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     let collective_action_hash =
         target_address
@@ -14,7 +26,7 @@ pub fn validate_create_link_owner_to_developer_collective(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link target".to_string()
             )))?;
-    let collective_record = must_get_valid_record(collective_action_hash)?;
+    let collective_record = must_get_valid_record(collective_action_hash.clone())?;
 
     let _developer_collective: crate::DeveloperCollective = collective_record
         .entry()
@@ -32,21 +44,80 @@ pub fn validate_create_link_owner_to_developer_collective(
             )))?;
 
     if owner_agent_key != action.author {
-        return Ok(ValidateCallbackResult::Invalid("Only the creator of a DeveloperCollective can create a DeveloperCollectiveToOwner link from that DeveloperCollective".into()));
+        return Ok(ValidateCallbackResult::Invalid(
+            "OwnerToDeveloperCollective links can only be created from the agent's own public key."
+                .into(),
+        ));
+    }
+
+    // Ownership is determined by walking the owner's chain back to the creation of the
+    // collective, so owner links from before that would be missed
+    if action.timestamp < collective_record.action().timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "OwnerToDeveloperCollective links cannot be older than the DeveloperCollective.".into(),
+        ));
+    }
+
+    if collective_record.action().author() == &action.author {
+        return Ok(ValidateCallbackResult::Valid);
     }
 
+    // Co-owners and recipients of an ownership transfer accept it by creating this link with
+    // the action hash of the CoOwnership or OwnershipTransfer in the tag
+    let grant_hash = ActionHash::from_raw_39(tag.0).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Link tag does not contain a valid action hash".into()
+        ))
+    })?;
+    let grant_record = must_get_valid_record(grant_hash.clone())?;
+    match owner_grant_from_record(&grant_record)? {
+        Some((for_collective, for_agent))
+            if for_agent == action.author && for_collective == collective_action_hash => {}
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Only owners of a DeveloperCollective can create an OwnerToDeveloperCollective link to it."
+                    .into(),
+            ))
+        }
+    }
+    if action.timestamp < grant_record.action().timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "OwnerToDeveloperCollective links cannot be older than the record in their tag.".into(),
+        ));
+    }
+    if ownership_transfer_from_record(&grant_record)?.is_some()
+        && has_responded_to_ownership_transfer(&action.author, &grant_hash, &action.prev_action)?
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The ownership transfer has already been accepted or declined.".into(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Rules:
+/// 1. Owner links can be deleted by the agent that created the link, or by an owner of the
+///    developer collective once they have deleted it. Ownership is determined on the owner's own
+///    chain, where a deletion by another agent doesn't show, so other owners remove co-owners
+///    by revoking their CoOwnership instead.
 pub fn validate_delete_link_owner_to_developer_collective(
     action: DeleteLink,
     original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    if action.author != original_action.author {
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let developer_collective_hash =
+        target
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link target".to_string()
+            )))?;
+    if !is_deleted_by_owner(&action, &developer_collective_hash)? {
         return Ok(ValidateCallbackResult::Invalid(
-            "Only the creator of a DeveloperCollectiveToOwner link can delete that link.".into(),
+            "Only the creator of an OwnerToDeveloperCollective link or owners that deleted the DeveloperCollective can delete that link.".into(),
         ));
     }
     Ok(ValidateCallbackResult::Valid)
@@ -0,0 +1,263 @@
+use hdi::prelude::*;
+
+use crate::{
+    agent_activity_since, app_entry_from_record, is_developer_collective_owner, EntryTypes,
+    LinkTypes,
+};
+
+/// Offer of an owner to hand a DeveloperCollective over to another agent. The offering agent
+/// ceases to be an owner as soon as the offer is made. The recipient either accepts the offer
+/// by creating an OwnerToDeveloperCollective link that carries the action hash of this entry in
+/// its tag, or declines it by deleting this entry. Both responses are made on the recipient's
+/// own source chain, so validation can ensure that an offer is responded to only once. After a
+/// decline, the offering agent becomes owner again by creating an OwnerToDeveloperCollective
+/// link that carries the action hash of the Delete in its tag.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct OwnershipTransfer {
+    pub for_collective: ActionHash,
+    pub to_agent: AgentPubKey,
+}
+
+/// Rules:
+/// 1. Only owners of the developer collective can offer to transfer it
+/// 2. Owners cannot transfer the collective to themselves
+pub fn validate_create_ownership_transfer(
+    action: EntryCreationAction,
+    ownership_transfer: OwnershipTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(ownership_transfer.for_collective.clone())?;
+    let _developer_collective: crate::DeveloperCollective = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    if &ownership_transfer.to_agent == action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A developer collective cannot be transferred to its own owner.".into(),
+        ));
+    }
+    if !is_developer_collective_owner(
+        action.author(),
+        &ownership_transfer.for_collective,
+        action.prev_action(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only owners of the developer collective can transfer it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_ownership_transfer(
+    _action: Update,
+    _ownership_transfer: OwnershipTransfer,
+    _original_action: EntryCreationAction,
+    _original_ownership_transfer: OwnershipTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "OwnershipTransfers cannot be updated",
+    )))
+}
+/// Rules:
+/// 1. Only the recipient can decline an ownership transfer
+/// 2. The decline may not be older than the offer
+/// 3. The recipient can only decline a transfer they have not accepted or declined before
+pub fn validate_delete_ownership_transfer(
+    action: Delete,
+    original_action: EntryCreationAction,
+    original_ownership_transfer: OwnershipTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_ownership_transfer.to_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the recipient of an ownership transfer can decline it.".into(),
+        ));
+    }
+    if &action.timestamp < original_action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership transfer cannot be declined before it has been offered.".into(),
+        ));
+    }
+    if has_responded_to_ownership_transfer(
+        &action.author,
+        &action.deletes_address,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The ownership transfer has already been accepted or declined.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Only the agent that offered the transfer can link it from the DeveloperCollective
+/// 2. Links from a DeveloperCollective can only point to transfers of that same DeveloperCollective
+/// 3. The link tag must contain the AgentPubKey of the recipient
+pub fn validate_create_link_developer_collective_to_ownership_transfers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let developer_collective_action_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let Some(ownership_transfer) = ownership_transfer_for_link(&action, target_address)? else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that offered an ownership transfer can create links to it.".into(),
+        ));
+    };
+    if ownership_transfer.for_collective != developer_collective_action_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links from developer collectives can only point to ownership transfers of that same developer collective."
+                .into(),
+        ));
+    }
+    let agent_in_tag = AgentPubKey::from_raw_39(tag.0).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Link tag does not contain a valid agent public key".into()
+        ))
+    })?;
+    if agent_in_tag != ownership_transfer.to_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag contains the wrong agent public key.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Only the agent that created the link can delete it
+pub fn validate_delete_link_developer_collective_to_ownership_transfers(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that offered an ownership transfer can delete links to it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Only the agent that offered the transfer can link it from the recipient
+/// 2. Links from an agent can only point to transfers to that same agent
+/// 3. The link tag must contain the action hash of the transferred DeveloperCollective
+pub fn validate_create_link_recipient_to_ownership_transfers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let recipient = base_address
+        .into_agent_pub_key()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No agent public key associated with link base".to_string()
+        )))?;
+    let Some(ownership_transfer) = ownership_transfer_for_link(&action, target_address)? else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that offered an ownership transfer can create links to it.".into(),
+        ));
+    };
+    if ownership_transfer.to_agent != recipient {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links from agents can only point to ownership transfers to that same agent.".into(),
+        ));
+    }
+    let collective_in_tag = ActionHash::from_raw_39(tag.0).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Link tag does not contain a valid action hash".into()
+        ))
+    })?;
+    if collective_in_tag != ownership_transfer.for_collective {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not contain the action hash of the transferred DeveloperCollective."
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Rules
+/// 1. Only the agent that created the link or the recipient can delete it
+pub fn validate_delete_link_recipient_to_ownership_transfers(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author
+        && base.into_agent_pub_key().as_ref() != Some(&action.author)
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that offered an ownership transfer or its recipient can delete links to it."
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Whether the recipient has accepted or declined the ownership transfer on their own source
+/// chain as of `chain_top`, i.e. created an OwnerToDeveloperCollective link that carries the
+/// action hash of the transfer in its tag, or deleted the transfer
+pub fn has_responded_to_ownership_transfer(
+    recipient: &AgentPubKey,
+    ownership_transfer_hash: &ActionHash,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    let ownership_transfer_record = must_get_valid_record(ownership_transfer_hash.clone())?;
+    // Neither response can be older than the offer
+    let activity = agent_activity_since(
+        recipient,
+        chain_top,
+        ownership_transfer_record.action().timestamp(),
+    )?;
+    for a in activity.iter() {
+        let responded = match a.action.action() {
+            Action::CreateLink(create_link) => {
+                matches!(
+                    LinkTypes::from_type(create_link.zome_index, create_link.link_type)?,
+                    Some(LinkTypes::OwnerToDeveloperCollective)
+                ) && create_link.tag.0 == ownership_transfer_hash.get_raw_39()
+            }
+            Action::Delete(delete) => &delete.deletes_address == ownership_transfer_hash,
+            _ => false,
+        };
+        if responded {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Gets the OwnershipTransfer a link points to, if it has been offered by the author of the link
+fn ownership_transfer_for_link(
+    action: &CreateLink,
+    target_address: AnyLinkableHash,
+) -> ExternResult<Option<OwnershipTransfer>> {
+    let ownership_transfer_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(ownership_transfer_action_hash)?;
+    if record.action().author() != &action.author {
+        return Ok(None);
+    }
+    ownership_transfer_from_record(&record)
+}
+
+/// Deserializes the record into an OwnershipTransfer only if it actually is of that entry type
+pub fn ownership_transfer_from_record(record: &Record) -> ExternResult<Option<OwnershipTransfer>> {
+    match app_entry_from_record(record)? {
+        Some(EntryTypes::OwnershipTransfer(ownership_transfer)) => Ok(Some(ownership_transfer)),
+        _ => Ok(None),
+    }
+}
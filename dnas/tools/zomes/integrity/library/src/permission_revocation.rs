@@ -0,0 +1,238 @@
+use hdi::prelude::*;
+
+use crate::{
+    agent_activity_since, app_entry_from_record, is_developer_collective_owner, EntryTypes,
+    LinkTypes,
+};
+
+/// Revokes a ContributorPermission, removes a co-owner by revoking their CoOwnership, or
+/// withdraws a ContributorInvitation. Unlike a Delete, a revocation is a record that validation
+/// can fetch: it is the latest state of the revoked permission, and actions that act with it
+/// are rejected if they are timestamped after it. Validation cannot prove that no revocation
+/// exists, so the revoked agent is notified and acknowledges the revocation on their own source
+/// chain with a RevocationAcknowledgements link, and coordinators look up the latest state of a
+/// permission before acting with it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct PermissionRevocation {
+    pub permission: ActionHash,
+}
+
+/// Rules:
+/// 1. Only ContributorPermissions, CoOwnerships and ContributorInvitations can be revoked, and a
+///    revocation may not be older than what it revokes
+/// 2. Only owners of the developer collective can revoke contributor permissions and withdraw
+///    invitations for it
+/// 3. A CoOwnership can be revoked by an owner of the developer collective or by the co-owner
+///    themselves
+pub fn validate_create_permission_revocation(
+    action: EntryCreationAction,
+    permission_revocation: PermissionRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    let permission_record = must_get_valid_record(permission_revocation.permission)?;
+    if action.timestamp() < &permission_record.action().timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A revocation cannot be older than what it revokes.".into(),
+        ));
+    }
+    match app_entry_from_record(&permission_record)? {
+        Some(EntryTypes::ContributorPermission(contributor_permission)) => {
+            if !is_developer_collective_owner(
+                action.author(),
+                &contributor_permission.for_collective,
+                action.prev_action(),
+            )? {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "Only owners of the developer collective can revoke contributor permissions for it."
+                        .into(),
+                ));
+            }
+        }
+        Some(EntryTypes::ContributorInvitation(contributor_invitation)) => {
+            if !is_developer_collective_owner(
+                action.author(),
+                &contributor_invitation.for_collective,
+                action.prev_action(),
+            )? {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "Only owners of the developer collective can withdraw invitations to it."
+                        .into(),
+                ));
+            }
+        }
+        Some(EntryTypes::CoOwnership(co_ownership)) => {
+            if action.author() != &co_ownership.for_agent
+                && !is_developer_collective_owner(
+                    action.author(),
+                    &co_ownership.for_collective,
+                    action.prev_action(),
+                )?
+            {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "Only owners of the developer collective can remove co-owners.".into(),
+                ));
+            }
+        }
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Only contributor permissions, co-ownerships and invitations can be revoked."
+                    .into(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_permission_revocation(
+    _action: Update,
+    _permission_revocation: PermissionRevocation,
+    _original_action: EntryCreationAction,
+    _original_permission_revocation: PermissionRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PermissionRevocations cannot be updated",
+    )))
+}
+pub fn validate_delete_permission_revocation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_permission_revocation: PermissionRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PermissionRevocations cannot be deleted",
+    )))
+}
+/// Rules
+/// 1. Only the agent that created the revocation can link it from the revoked permission
+/// 2. The link must point from the revoked permission to its revocation
+pub fn validate_create_link_permission_revocations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let permission_action_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let revocation_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let revocation_record = must_get_valid_record(revocation_action_hash)?;
+    if revocation_record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that revoked a permission can link the revocation.".into(),
+        ));
+    }
+    let Some(permission_revocation) = permission_revocation_from_record(&revocation_record)? else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link target must be a PermissionRevocation entry.".into(),
+        ));
+    };
+    if permission_revocation.permission != permission_action_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links to revocations can only be created from the permission they revoke.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_permission_revocations(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PermissionRevocations links cannot be deleted",
+    )))
+}
+/// Rules
+/// 1. Agents can only acknowledge revocations from their own public key
+/// 2. The link must point to the PermissionRevocation and carry the action hash of the revoked
+///    grant in its tag, i.e. of the CoOwnership or of the first ContributorPermission of the
+///    renewal chain
+///
+/// The revoked agent acknowledges while the revocation is being committed, so the revocation
+/// can't be fetched here. An acknowledgement only restricts the agent that created it.
+pub fn validate_create_link_revocation_acknowledgements(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address.into_agent_pub_key().as_ref() != Some(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Revocations can only be acknowledged from the agent's own public key.".into(),
+        ));
+    }
+    if target_address.into_action_hash().is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "No action hash associated with link".into(),
+        ));
+    }
+    if ActionHash::from_raw_39(tag.0).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not contain a valid action hash".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_revocation_acknowledgements(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "RevocationAcknowledgements links cannot be deleted",
+    )))
+}
+
+/// Whether `agent` has acknowledged a revocation of the grant on its own source chain as of
+/// `chain_top`. Acknowledgements older than `since`, the creation of the grant, are not
+/// considered.
+pub fn has_acknowledged_revocation(
+    agent: &AgentPubKey,
+    grant_hash: &ActionHash,
+    chain_top: &ActionHash,
+    since: Timestamp,
+) -> ExternResult<bool> {
+    for a in agent_activity_since(agent, chain_top, since)? {
+        if is_revocation_acknowledgement(a.action.action(), grant_hash)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Whether the action is a RevocationAcknowledgements link for the grant
+pub fn is_revocation_acknowledgement(
+    action: &Action,
+    grant_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let Action::CreateLink(create_link) = action else {
+        return Ok(false);
+    };
+    Ok(matches!(
+        LinkTypes::from_type(create_link.zome_index, create_link.link_type)?,
+        Some(LinkTypes::RevocationAcknowledgements)
+    ) && create_link.tag.0 == grant_hash.get_raw_39())
+}
+
+/// Deserializes the record into a PermissionRevocation only if it actually is of that entry type
+pub fn permission_revocation_from_record(
+    record: &Record,
+) -> ExternResult<Option<PermissionRevocation>> {
+    match app_entry_from_record(record)? {
+        Some(EntryTypes::PermissionRevocation(permission_revocation)) => {
+            Ok(Some(permission_revocation))
+        }
+        _ => Ok(None),
+    }
+}
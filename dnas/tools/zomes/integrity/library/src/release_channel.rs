@@ -0,0 +1,67 @@
+use hdi::prelude::*;
+
+/// Release channel of a Tool revision. Channels are ordered from most to least stable,
+/// so that agents following a less stable channel also get releases of the more stable
+/// channels.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub enum ReleaseChannel {
+    #[default]
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl ReleaseChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseChannel::Stable => "stable",
+            ReleaseChannel::Beta => "beta",
+            ReleaseChannel::Nightly => "nightly",
+        }
+    }
+
+    pub fn from_name(channel: &str) -> Option<Self> {
+        match channel {
+            "stable" => Some(ReleaseChannel::Stable),
+            "beta" => Some(ReleaseChannel::Beta),
+            "nightly" => Some(ReleaseChannel::Nightly),
+            _ => None,
+        }
+    }
+
+    /// Whether agents following this channel should get releases published to `channel`
+    pub fn includes(&self, channel: &ReleaseChannel) -> bool {
+        channel <= self
+    }
+}
+
+/// The tag of a ToolUpdates link contains the raw permission action hash followed by the
+/// name of the release channel of the Tool update it points to
+pub fn tool_updates_link_tag(permission_hash: &ActionHash, channel: &ReleaseChannel) -> LinkTag {
+    let mut tag = permission_hash.get_raw_39().to_vec();
+    tag.extend_from_slice(channel.as_str().as_bytes());
+    LinkTag::new(tag)
+}
+
+/// Parses the permission action hash and release channel from the tag of a ToolUpdates link.
+/// Links created before release channels existed only contain the permission action hash and
+/// point to stable releases.
+pub fn parse_tool_updates_link_tag(tag: &LinkTag) -> Result<(ActionHash, ReleaseChannel), String> {
+    if tag.0.len() < 39 {
+        return Err("Link tag is too short to contain a permission action hash.".into());
+    }
+    let (permission_hash, channel) = tag.0.split_at(39);
+    let permission_hash = ActionHash::from_raw_39(permission_hash.to_vec()).map_err(|e| {
+        format!("Link tag does not contain a valid action hash. Conversion failed with error: {e}")
+    })?;
+    if channel.is_empty() {
+        return Ok((permission_hash, ReleaseChannel::Stable));
+    }
+    let channel = std::str::from_utf8(channel)
+        .ok()
+        .and_then(ReleaseChannel::from_name)
+        .ok_or(String::from(
+            "Link tag does not contain a valid release channel.",
+        ))?;
+    Ok((permission_hash, channel))
+}
@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+
+use hdi::prelude::*;
+
+use crate::{is_developer_collective_owner, original_action_hash, Tool};
+
+/// Words are indexed by their prefixes of these lengths only, so that a word doesn't create a
+/// link for every prefix. Search results are filtered by the full words of the query afterwards.
+pub const SEARCH_TERM_LENGTHS: [usize; 3] = [3, 5, 8];
+
+/// Splits a text into lowercase alphanumeric words
+pub fn normalize_search_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+/// Gets the search term that a word of a search query is looked up by, i.e. its longest indexed
+/// prefix, or None if the word is too short to be indexed
+pub fn search_term_for_word(word: &str) -> Option<String> {
+    let chars: Vec<char> = word.chars().collect();
+    SEARCH_TERM_LENGTHS
+        .iter()
+        .rev()
+        .find(|length| **length <= chars.len())
+        .map(|length| chars[..*length].iter().collect())
+}
+
+/// Gets the search terms that a text is indexed by: the prefixes of every word with one of the
+/// indexed lengths, so that a Tool can be found while typing
+pub fn search_terms(text: &str) -> BTreeSet<String> {
+    let mut terms = BTreeSet::new();
+    for word in normalize_search_words(text) {
+        let chars: Vec<char> = word.chars().collect();
+        for length in SEARCH_TERM_LENGTHS {
+            if length <= chars.len() {
+                terms.insert(chars[..length].iter().collect());
+            }
+        }
+    }
+    terms
+}
+
+impl Tool {
+    /// The search terms that this Tool can be found by
+    pub fn search_terms(&self) -> BTreeSet<String> {
+        let mut terms = search_terms(&self.title);
+        terms.extend(search_terms(&self.subtitle));
+        for tag in &self.tags {
+            terms.extend(search_terms(tag));
+        }
+        terms
+    }
+
+    /// The words of the title, subtitle and tags of this Tool
+    pub fn search_words(&self) -> BTreeSet<String> {
+        let mut words: BTreeSet<String> = normalize_search_words(&self.title).into_iter().collect();
+        words.extend(normalize_search_words(&self.subtitle));
+        for tag in &self.tags {
+            words.extend(normalize_search_words(tag));
+        }
+        words
+    }
+}
+
+/// Gets the path that Tools indexed by the search term are linked from
+pub fn search_term_path(term: &str) -> Path {
+    Path::from(format!("search_terms.{term}"))
+}
+
+/// The tag of a SearchTermToTools link contains the raw action hash of the original Tool,
+/// so that search results can be intersected without fetching them, followed by the term
+pub fn search_term_link_tag(original_tool_hash: &ActionHash, term: &str) -> LinkTag {
+    let mut tag = original_tool_hash.get_raw_39().to_vec();
+    tag.extend_from_slice(term.as_bytes());
+    LinkTag::new(tag)
+}
+
+/// Parses the original Tool action hash and the search term from the tag of a
+/// SearchTermToTools link
+pub fn parse_search_term_link_tag(tag: &LinkTag) -> Result<(ActionHash, String), String> {
+    if tag.0.len() < 39 {
+        return Err("Link tag is too short to contain an action hash.".into());
+    }
+    let (original_tool_hash, term) = tag.0.split_at(39);
+    let original_tool_hash = ActionHash::from_raw_39(original_tool_hash.to_vec()).map_err(|e| {
+        format!("Link tag does not contain a valid action hash. Conversion failed with error: {e}")
+    })?;
+    let term = String::from_utf8(term.to_vec())
+        .map_err(|_| String::from("Link tag does not contain a valid search term."))?;
+    Ok((original_tool_hash, term))
+}
+
+/// Rules
+/// 1. The link tag must contain the action hash of the original Tool and a search term
+/// 2. The link must point away from the path of that search term
+/// 3. The link must point to a revision of the original Tool whose title, subtitle or tags
+///    contain the search term
+/// 4. Only the agent that created the revision can link it from its search terms
+pub fn validate_create_link_search_term_to_tools(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (original_tool_hash, term) = match parse_search_term_link_tag(&tag) {
+        Ok(parsed) => parsed,
+        Err(e) => return Ok(ValidateCallbackResult::Invalid(e)),
+    };
+    if base_address.into_entry_hash() != Some(search_term_path(&term).path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "SearchTermToTools link is not pointing away from the path of its search term.".into(),
+        ));
+    }
+    let tool_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(tool_action_hash.clone())?;
+    let tool: crate::Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a Tool entry".to_string()
+        )))?;
+    if original_action_hash(tool_action_hash)? != original_tool_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not contain the action hash of the original Tool.".into(),
+        ));
+    }
+    if !tool.search_terms().contains(&term) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The search term does not appear in the title, subtitle or tags of the Tool.".into(),
+        ));
+    }
+    if &action.author != record.action().author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that created a Tool revision can link it from its search terms.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the agent that created the link or an owner of the Tool's DeveloperCollective can
+///    delete the link
+pub fn validate_delete_link_search_term_to_tools(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let tool_action_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let record = must_get_valid_record(tool_action_hash)?;
+    let tool: crate::Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a Tool entry".to_string()
+        )))?;
+    if is_developer_collective_owner(
+        &action.author,
+        &tool.developer_collective,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Search term links can only be deleted by the agent that created the link or owners of the DeveloperCollective.".into(),
+    ))
+}
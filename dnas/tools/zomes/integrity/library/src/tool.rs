@@ -1,28 +1,154 @@
 use hdi::prelude::*;
 
+use crate::{
+    co_ownership_from_record, has_acknowledged_revocation, is_developer_collective_owner,
+    is_index_base, original_action_hash, owner_grant_from_record, parse_tool_updates_link_tag,
+    permission_revocation_from_record, renewal_chain_root, validate_icon_ref, validate_tool_hashes,
+    validate_tool_source, validate_tool_source_manifest, validate_tool_tags, ContributorRole,
+    IconRef, ReleaseChannel, ToolHashes, ToolSource,
+};
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Tool {
     pub developer_collective: ActionHash,
-    pub permission_hash: ActionHash, // Either the CreateAction hash of the DeveloperCollective entry or an ActionHash of a ContributorPermission entry
+    pub permission_hash: ActionHash, // Either the CreateAction hash of the DeveloperCollective entry or an ActionHash of a CoOwnership or ContributorPermission entry
     pub title: String,
     pub subtitle: String,
     pub description: String,
-    pub icon: String, // base64 string
-    pub version: String,
-    pub source: String, // JSON string containing information about where to get this Tool from
-    pub hashes: String, // Hashes related to this Tool to verify its integrity
+    pub icon: IconRef,
+    pub version: String, // Semantic version, e.g. 0.1.3 or 1.0.0-beta.2
+    pub source: ToolSource,
+    pub hashes: ToolHashes,
     pub changelog: Option<String>,
     pub meta_data: Option<String>,
     pub deprecation: Option<String>,
+    #[serde(default)] // Tool entries published before channels existed are stable releases
+    pub channel: ReleaseChannel,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Tool {
+    /// Parses the version of this Tool as a semantic version. Tool entries published
+    /// before versions were validated may not have one.
+    pub fn semver(&self) -> Option<semver::Version> {
+        semver::Version::parse(&self.version).ok()
+    }
+
+    /// Whether `updated` changes anything else than the descriptive metadata of this Tool
+    pub fn is_release_change(&self, updated: &Tool) -> bool {
+        self.source != updated.source
+            || self.hashes != updated.hashes
+            || self.version != updated.version
+            || self.channel != updated.channel
+            || self.changelog != updated.changelog
+            || self.deprecation != updated.deprecation
+    }
+}
+
+const TITLE_MAX_LENGTH: usize = 100;
+const SUBTITLE_MAX_LENGTH: usize = 200;
+const DESCRIPTION_MAX_LENGTH: usize = 5_000;
+const VERSION_MAX_LENGTH: usize = 100;
+const CHANGELOG_MAX_LENGTH: usize = 50_000;
+const META_DATA_MAX_LENGTH: usize = 5_000_000;
+const DEPRECATION_MAX_LENGTH: usize = 1_000;
+
+fn validate_tool_fields(tool: &Tool) -> ValidateCallbackResult {
+    if tool.title.trim().is_empty() {
+        return ValidateCallbackResult::Invalid("Title may not be empty.".into());
+    }
+    if tool.title.chars().count() > TITLE_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Title may be no longer than {TITLE_MAX_LENGTH} characters."
+        ));
+    }
+    if tool.subtitle.chars().count() > SUBTITLE_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Subtitle may be no longer than {SUBTITLE_MAX_LENGTH} characters."
+        ));
+    }
+    if tool.description.chars().count() > DESCRIPTION_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Description may be no longer than {DESCRIPTION_MAX_LENGTH} characters."
+        ));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_tags(&tool.tags) {
+        return ValidateCallbackResult::Invalid(e);
+    }
+    if tool.version.chars().count() > VERSION_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Version may be no longer than {VERSION_MAX_LENGTH} characters."
+        ));
+    }
+    if let Some(changelog) = &tool.changelog {
+        if changelog.chars().count() > CHANGELOG_MAX_LENGTH {
+            return ValidateCallbackResult::Invalid(format!(
+                "Changelog may be no longer than {CHANGELOG_MAX_LENGTH} characters."
+            ));
+        }
+    }
+    if let Some(meta_data) = &tool.meta_data {
+        if meta_data.chars().count() > META_DATA_MAX_LENGTH {
+            return ValidateCallbackResult::Invalid(format!(
+                "Metadata information may be no longer than {META_DATA_MAX_LENGTH} characters."
+            ));
+        }
+    }
+    if let Some(deprecation) = &tool.deprecation {
+        if deprecation.chars().count() > DEPRECATION_MAX_LENGTH {
+            return ValidateCallbackResult::Invalid(format!(
+                "Deprecation notice may be no longer than {DEPRECATION_MAX_LENGTH} characters."
+            ));
+        }
+    }
+    ValidateCallbackResult::Valid
 }
+
+fn validate_version(version: &str) -> ValidateCallbackResult {
+    match semver::Version::parse(version) {
+        Ok(_) => ValidateCallbackResult::Valid,
+        Err(e) => ValidateCallbackResult::Invalid(format!(
+            "Version '{version}' is not a valid semantic version: {e}"
+        )),
+    }
+}
+
 /// Rules:
-/// 1. Only the creator of a DeveloperCollective entry or an agent with a valid ContributorPermission
-///    can create a Tool for a DeveloperCollective
+/// 1. Only owners of a DeveloperCollective or an agent with a valid ContributorPermission for the
+///    Maintainer role that is not restricted to specific Tools can create a Tool for a
+///    DeveloperCollective
+/// 2. The source must be a valid, typed ToolSource, artifacts stored on the DHT must refer to
+///    a FileManifest whose Sha256 digest the Tool declares in its hashes
+/// 3. The hashes must be a list of well-formed artifact hashes
+/// 4. The version must be a valid semantic version
+/// 5. All fields must be within their length limits and the icon must refer to an Icon entry
 pub fn validate_create_tool(
     action: EntryCreationAction,
     tool: Tool,
 ) -> ExternResult<ValidateCallbackResult> {
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_fields(&tool) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_icon_ref(&tool.icon)? {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_version(&tool.version) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_source(&tool.source) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) =
+        validate_tool_source_manifest(&tool.source, &tool.hashes)?
+    {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_hashes(&tool.hashes) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+
     let record = must_get_valid_record(tool.developer_collective.clone())?;
     let _developer_collective: crate::DeveloperCollective = record
         .entry()
@@ -36,7 +162,9 @@ pub fn validate_create_tool(
         tool.permission_hash,
         action.author().clone(),
         tool.developer_collective,
-        action.timestamp().clone(),
+        *action.timestamp(),
+        action.prev_action().clone(),
+        ToolAction::Publish,
     )
 }
 
@@ -45,6 +173,18 @@ pub fn validate_create_tool(
 /// 2. Only the creator of a DevloperCollective or agents with ContributorPermission for
 ///    the DeveloperCollective the Tool is published under (by being referenced in the
 ///    developer_collective field) are allowed to update a Tool
+/// 3. The source and hashes must be typed and valid, even if the original Tool still had
+///    legacy ones, and artifacts stored on the DHT must refer to a FileManifest whose Sha256
+///    digest the Tool declares in its hashes
+/// 4. The version must be a valid semantic version and may not be lower than the version of
+///    the revision it supersedes on its release channel, i.e. the nearest revision with the
+///    same channel among the Tool entry being updated and the revisions it descends from.
+///    The rule applies along the revisions an update descends from rather than to the latest
+///    revision of the Tool: updating an older revision, e.g. to publish a patch of an older
+///    release, only needs to keep up with the revisions that update descends from. The
+///    superseded revision may be at most MAX_REVISIONS_TO_SUPERSEDED revisions back.
+/// 5. All fields must be within their length limits and the icon must refer to an Icon entry
+/// 6. The role and tool scope of a ContributorPermission must allow the changes
 pub fn validate_update_tool(
     action: Update,
     tool: Tool,
@@ -56,16 +196,92 @@ pub fn validate_update_tool(
             "The developer_collective field may not be updated.".into(),
         ));
     }
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_fields(&tool) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_icon_ref(&tool.icon)? {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_source(&tool.source) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) =
+        validate_tool_source_manifest(&tool.source, &tool.hashes)?
+    {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if let ValidateCallbackResult::Invalid(e) = validate_tool_hashes(&tool.hashes) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    let Some(version) = tool.semver() else {
+        return Ok(validate_version(&tool.version));
+    };
+    let superseded_tool = superseded_revision_on_channel(
+        action.original_action_address.clone(),
+        original_tool.clone(),
+        &tool.channel,
+    )?;
+    if let Some(superseded_version) = superseded_tool.and_then(|tool| tool.semver()) {
+        if version.cmp_precedence(&superseded_version).is_lt() {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The version of a Tool may not be decreased (from {superseded_version} to {version})."
+            )));
+        }
+    }
 
+    let tool_action = ToolAction::Edit {
+        original_tool_hash: original_action_hash(action.original_action_address.clone())?,
+        changes_release: original_tool.is_release_change(&tool),
+    };
     validate_contributor_permission(
         tool.permission_hash,
         action.author,
         original_tool.developer_collective,
         action.timestamp,
+        action.prev_action,
+        tool_action,
     )
 }
+/// How many revisions on other release channels an update may be away from the revision it
+/// supersedes on its own channel. Each step fetches a revision, so the walk is capped.
+const MAX_REVISIONS_TO_SUPERSEDED: usize = 100;
+/// Follows the revisions that `tool_hash` descends from back to the nearest one published to
+/// `channel`, starting with `tool_hash` itself. Returns None if no such revision exists, e.g.
+/// for the first beta release of a Tool that only had stable releases before. Fails if the
+/// revision is more than MAX_REVISIONS_TO_SUPERSEDED revisions back, in which case an update
+/// has to be made to a more recent revision of the channel instead.
+fn superseded_revision_on_channel(
+    tool_hash: ActionHash,
+    tool: Tool,
+    channel: &ReleaseChannel,
+) -> ExternResult<Option<Tool>> {
+    let mut tool_hash = tool_hash;
+    let mut tool = tool;
+    let mut steps = 0;
+    while &tool.channel != channel {
+        steps += 1;
+        if steps > MAX_REVISIONS_TO_SUPERSEDED {
+            return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                "The revision on the {} channel is more than {MAX_REVISIONS_TO_SUPERSEDED} revisions back, update a more recent revision of the channel instead.",
+                channel.as_str()
+            ))));
+        }
+        let Action::Update(update) = must_get_action(tool_hash)?.action().clone() else {
+            return Ok(None);
+        };
+        tool_hash = update.original_action_address;
+        tool = must_get_valid_record(tool_hash.clone())?
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Tool revisions can only update Tool entries".to_string()
+            )))?;
+    }
+    Ok(Some(tool))
+}
 /// Rules:
-/// 1. Only the creator of a DeveloperCollective entry or the agent that originally created the Tool
+/// 1. Only owners of a DeveloperCollective or the agent that originally created the Tool
 ///    can delete a Tool for a DeveloperCollective
 pub fn validate_delete_tool(
     action: Delete,
@@ -75,11 +291,14 @@ pub fn validate_delete_tool(
     if &action.author == original_action.author() {
         Ok(ValidateCallbackResult::Valid)
     } else {
-        let collective_record = must_get_valid_record(original_tool.developer_collective)?;
-        if collective_record.action().author() == &action.author {
+        if is_developer_collective_owner(
+            &action.author,
+            &original_tool.developer_collective,
+            &action.prev_action,
+        )? {
             return Ok(ValidateCallbackResult::Valid);
         }
-        Ok(ValidateCallbackResult::Invalid("Only the creator of the DeveloperCollective entry or the creator of the link is allowed to delete a link from a DeveloperCollective entry to a Tool entry".into()))
+        Ok(ValidateCallbackResult::Invalid("Only owners of the DeveloperCollective or the creator of the Tool are allowed to delete a Tool".into()))
     }
 }
 /// Rules:
@@ -110,12 +329,17 @@ pub fn validate_create_link_developer_collective_to_tools(
                 "No action hash associated with link".to_string()
             )))?;
 
-    validate_contributor_permission(
+    match validate_contributor_permission(
         permission_action_hash,
         action.author,
         collective_action_hash.clone(),
         action.timestamp,
-    )?;
+        action.prev_action,
+        ToolAction::Publish,
+    )? {
+        ValidateCallbackResult::Valid => (),
+        result => return Ok(result),
+    }
 
     let collective_record = must_get_valid_record(collective_action_hash.clone())?;
     let _developer_collective: crate::DeveloperCollective = collective_record
@@ -159,7 +383,7 @@ pub fn validate_delete_link_developer_collective_to_tools(
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Only the agent that created the link or the creator of the DeveloperCollective entry can remove such a link
+    // Only the agent that created the link or an owner of the DeveloperCollective can remove such a link
     if action.author == original_action.author {
         Ok(ValidateCallbackResult::Valid)
     } else {
@@ -169,12 +393,15 @@ pub fn validate_delete_link_developer_collective_to_tools(
                 "Link base is not an action hash. This link should never have passed validation in the first place!".to_string()
             )))?;
 
-        let collective_record = must_get_valid_record(collective_action_hash)?;
-        if collective_record.action().author() == &action.author {
+        if is_developer_collective_owner(
+            &action.author,
+            &collective_action_hash,
+            &action.prev_action,
+        )? {
             return Ok(ValidateCallbackResult::Valid);
         }
 
-        Ok(ValidateCallbackResult::Invalid("Only the creator of the DeveloperCollective entry or the creator of the link is allowed to delete a link from a DeveloperCollective entry to a Tool entry".into()))
+        Ok(ValidateCallbackResult::Invalid("Only owners of the DeveloperCollective or the creator of the link are allowed to delete a link from a DeveloperCollective entry to a Tool entry".into()))
     }
 }
 
@@ -184,20 +411,16 @@ pub fn validate_delete_link_developer_collective_to_tools(
 ///    incentive to create fake UpdateLinks as they could just as well update the Tool itself. So we don't
 ///    need to add more complex validation logic to ensure that the update link poinst away from the right
 ///    Tool to the actual update of the Tool
+/// 2. The release channel in the link tag must be the channel of the Tool update the link points to
 pub fn validate_create_link_tool_updates(
     action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let permission_action_hash = match ActionHash::from_raw_39(tag.0) {
-        Ok(ah) => ah,
-        Err(e) => {
-            return Ok(ValidateCallbackResult::Invalid(format!(
-                "Link tag does not contain a valid action hash. Conversion failed with error: {}",
-                e
-            )))
-        }
+    let (permission_action_hash, channel) = match parse_tool_updates_link_tag(&tag) {
+        Ok(parsed) => parsed,
+        Err(e) => return Ok(ValidateCallbackResult::Invalid(e)),
     };
 
     let tool_action_hash =
@@ -206,7 +429,7 @@ pub fn validate_create_link_tool_updates(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link".to_string()
             )))?;
-    let tool_record = must_get_valid_record(tool_action_hash)?;
+    let tool_record = must_get_valid_record(tool_action_hash.clone())?;
 
     let tool: crate::Tool = tool_record
         .entry()
@@ -216,12 +439,22 @@ pub fn validate_create_link_tool_updates(
             "Linked action must reference an entry".to_string()
         )))?;
 
-    validate_contributor_permission(
+    // The Tool update itself has already been validated against the role of the permission
+    let tool_action = ToolAction::Edit {
+        original_tool_hash: original_action_hash(tool_action_hash)?,
+        changes_release: false,
+    };
+    match validate_contributor_permission(
         permission_action_hash,
         action.author,
         tool.developer_collective,
         action.timestamp,
-    )?;
+        action.prev_action,
+        tool_action,
+    )? {
+        ValidateCallbackResult::Valid => (),
+        result => return Ok(result),
+    }
 
     // Check the entry type for the given action hash
     let tool_update_action_hash =
@@ -231,7 +464,7 @@ pub fn validate_create_link_tool_updates(
                 "No action hash associated with link".to_string()
             )))?;
     let tool_update_record = must_get_valid_record(tool_update_action_hash)?;
-    let _tool: crate::Tool = tool_update_record
+    let tool_update: crate::Tool = tool_update_record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -239,6 +472,13 @@ pub fn validate_create_link_tool_updates(
             "Linked action must reference an entry".to_string()
         )))?;
 
+    if tool_update.channel != channel {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The release channel in the link tag does not match the channel of the Tool update."
+                .into(),
+        ));
+    }
+
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -256,25 +496,155 @@ pub fn validate_delete_link_tool_updates(
     )))
 }
 
-/// Validates for
+/// Rules
+/// 1. The link must point away from the shard of the all_tools anchor that belongs to the Tool
+/// 2. The link must point to a Tool entry
+/// 3. Only the agent that created the Tool can link it from the all_tools anchor
+pub fn validate_create_link_all_tools(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_index_base("all_tools", &base_address, &target_address)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllTools link is not pointing away from the all_tools anchor shard of its target."
+                .into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _tool: crate::Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a Tool entry".to_string()
+        )))?;
+    if &action.author != record.action().author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Links from the all_tools anchor to a Tool entry can only be created by the agent that created the Tool entry.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the agent that created the link or an owner of the Tool's DeveloperCollective can
+///    delete the link, the same agents that may delete the Tool itself
+pub fn validate_delete_link_all_tools(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let action_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let tool: crate::Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a Tool entry".to_string()
+        )))?;
+    if is_developer_collective_owner(
+        &action.author,
+        &tool.developer_collective,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Links from the all_tools anchor can only be deleted by the agent that created the link or owners of the DeveloperCollective.".into(),
+    ))
+}
+
+/// What an agent does with a Tool on behalf of a DeveloperCollective, to be checked against the
+/// role and tool scope of their ContributorPermission
+pub enum ToolAction {
+    /// Publishing a new Tool under the DeveloperCollective
+    Publish,
+    /// Changing an existing Tool, identified by the action hash of its original Create action
+    Edit {
+        original_tool_hash: ActionHash,
+        changes_release: bool,
+    },
+}
+
+/// Validates that `agent` was allowed to act on behalf of the DeveloperCollective at `timestamp`
+/// with the given permission, i.e. that it is an owner of the collective as of `chain_top` on its
+/// own source chain or holds a ContributorPermission for it that had not expired by then and
+/// whose role and tool scope allow the `tool_action`.
+///
+/// Owners use the Create action hash of the DeveloperCollective (its creator), their CoOwnership
+/// or the OwnershipTransfer they accepted as permission hash.
+///
+/// Contributors use the latest state of their ContributorPermission, i.e. its latest renewal or
+/// the PermissionRevocation of it. Removed co-owners likewise use the PermissionRevocation of
+/// their CoOwnership. Actions with a revocation are only valid if they are
+/// timestamped before it. Validation cannot find revocations that the permission hash doesn't
+/// lead to, so it relies on the contributor's acknowledgement of a revocation on their own
+/// chain, which rejects every permission of the renewal chain. Until the contributor has been
+/// notified, only coordinator functions, which look up the latest state before acting, stop
+/// them from using an earlier permission of the chain.
+///
+/// Callers must return any result other than `Valid` rather than discarding it.
 pub fn validate_contributor_permission(
     permission_hash: ActionHash,
     agent: AgentPubKey,
     developer_collective_hash: ActionHash,
     timestamp: Timestamp,
+    chain_top: ActionHash,
+    tool_action: ToolAction,
 ) -> ExternResult<ValidateCallbackResult> {
-    let permission_record = must_get_valid_record(permission_hash.clone())?;
-
-    // If it's the creator of the DeveloperCollective, i.e. the permission hash is the
-    // Create action of the DeveloperCollective itself, get the DeveloperCollective entry
-    // and validate that this agent is indeed the creator of the DeveloperCollective
-    if permission_hash == developer_collective_hash {
-        let developer_collective_record = must_get_valid_record(developer_collective_hash)?;
-        if developer_collective_record.action().author() == &agent {
+    let mut permission_record = must_get_valid_record(permission_hash)?;
+    if let Some(permission_revocation) = permission_revocation_from_record(&permission_record)? {
+        let revoked_record = must_get_valid_record(permission_revocation.permission)?;
+        if permission_record.action().timestamp() <= timestamp {
+            return Ok(ValidateCallbackResult::Invalid(
+                match co_ownership_from_record(&revoked_record)? {
+                    Some(_) => "CoOwnership has been revoked.",
+                    None => "ContributorPermission has been revoked.",
+                }
+                .into(),
+            ));
+        }
+        permission_record = revoked_record;
+    }
+    let permission_hash = permission_record.action_address().clone();
+    let owner_grant = owner_grant_from_record(&permission_record)?;
+
+    if permission_hash == developer_collective_hash || owner_grant.is_some() {
+        if let Some((for_collective, for_agent)) = owner_grant {
+            if for_agent != agent {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "Owner permission is for the wrong agent.".into(),
+                ));
+            }
+            if for_collective != developer_collective_hash {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "Owner permission is for the wrong DeveloperCollective.".into(),
+                ));
+            }
+        }
+        if is_developer_collective_owner(&agent, &developer_collective_hash, &chain_top)? {
             Ok(ValidateCallbackResult::Valid)
         } else {
             Ok(ValidateCallbackResult::Invalid(
-                "Permission claims to have Creator permission but that's not the case.".into(),
+                "Permission claims to have owner permission but that's not the case.".into(),
             ))
         }
     } else {
@@ -310,6 +680,51 @@ pub fn validate_contributor_permission(
             }
         }
 
+        // 4. Validate that the contributor has not acknowledged a revocation of any permission
+        //    of the renewal chain
+        let root_record = renewal_chain_root(&permission_record)?;
+        if has_acknowledged_revocation(
+            &agent,
+            root_record.action_address(),
+            &chain_top,
+            root_record.action().timestamp(),
+        )? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "ContributorPermission has been revoked.".into(),
+            ));
+        }
+
+        // 5. Validate that the role and tool scope of the permission allow the action
+        match tool_action {
+            ToolAction::Publish => {
+                if contributor_permission.role != ContributorRole::Maintainer
+                    || contributor_permission.tools.is_some()
+                {
+                    return Ok(ValidateCallbackResult::Invalid(
+                        "ContributorPermission does not allow publishing new Tools.".into(),
+                    ));
+                }
+            }
+            ToolAction::Edit {
+                original_tool_hash,
+                changes_release,
+            } => {
+                if let Some(tools) = &contributor_permission.tools {
+                    if !tools.contains(&original_tool_hash) {
+                        return Ok(ValidateCallbackResult::Invalid(
+                            "ContributorPermission does not cover this Tool.".into(),
+                        ));
+                    }
+                }
+                if changes_release && contributor_permission.role == ContributorRole::MetadataEditor
+                {
+                    return Ok(ValidateCallbackResult::Invalid(
+                        "ContributorPermission only allows editing the metadata of Tools.".into(),
+                    ));
+                }
+            }
+        }
+
         Ok(ValidateCallbackResult::Valid)
     }
 }
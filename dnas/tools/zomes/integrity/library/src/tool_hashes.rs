@@ -0,0 +1,183 @@
+use hdi::prelude::*;
+
+/// Hashes related to a Tool to verify its integrity.
+///
+/// Like `ToolSource`, Tool entries published before hashes were typed carry a free-form
+/// string which still deserializes into the `Legacy` variant, but new Tool entries and
+/// updates must use the structured form.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum ToolHashes {
+    V1(Vec<ArtifactHash>),
+    Legacy(String),
+}
+
+/// The hash of a single artifact of a Tool release
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ArtifactHash {
+    pub artifact: Artifact,
+    pub algorithm: HashAlgorithm,
+    pub digest: String, // lowercase hex string, or the base64 encoded hash for HolochainDnaHash
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type")]
+pub enum Artifact {
+    WebHapp,
+    Happ,
+    UiZip,
+    Dna { role_name: String },
+    Zome { role_name: String, zome_name: String },
+}
+
+/// Happ and webhapp hashes are the Sha256 hashes of the respective files.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha512,
+    Blake2b256,
+    /// The DnaHash as computed by Holochain, base64 encoded with the `uhC0k` prefix
+    HolochainDnaHash,
+}
+
+const ARTIFACT_HASHES_MAX_COUNT: usize = 100;
+const ROLE_NAME_MAX_LENGTH: usize = 100;
+const ZOME_NAME_MAX_LENGTH: usize = 100;
+const DNA_HASH_B64_PREFIX: &str = "uhC0k";
+const DNA_HASH_B64_LENGTH: usize = 53;
+
+impl HashAlgorithm {
+    /// Number of hex characters of a digest of this algorithm, if it is hex encoded
+    pub fn hex_digest_length(&self) -> Option<usize> {
+        match self {
+            HashAlgorithm::Sha256 => Some(64),
+            HashAlgorithm::Sha512 => Some(128),
+            HashAlgorithm::Blake2b256 => Some(64),
+            HashAlgorithm::HolochainDnaHash => None,
+        }
+    }
+}
+
+impl Artifact {
+    /// DNA hashes only exist for DNAs
+    fn supports(&self, algorithm: &HashAlgorithm) -> bool {
+        match algorithm {
+            HashAlgorithm::HolochainDnaHash => matches!(self, Artifact::Dna { .. }),
+            _ => true,
+        }
+    }
+}
+
+/// Rules:
+/// 1. Legacy (unstructured) hashes may not be used anymore for new Tool entries or updates
+/// 2. There may be at most one hash per artifact and algorithm
+/// 3. Digests must be well-formed for their algorithm, i.e. lowercase hex of the right length
+///    or a base64 encoded DnaHash
+/// 4. DnaHash digests can only be declared for DNA artifacts
+pub fn validate_tool_hashes(hashes: &ToolHashes) -> ValidateCallbackResult {
+    let hashes = match hashes {
+        ToolHashes::V1(hashes) => hashes,
+        ToolHashes::Legacy(_) => {
+            return ValidateCallbackResult::Invalid(
+                "Unstructured Tool hashes are no longer supported. Use a list of artifact hashes."
+                    .into(),
+            )
+        }
+    };
+    if hashes.len() > ARTIFACT_HASHES_MAX_COUNT {
+        return ValidateCallbackResult::Invalid(format!(
+            "A Tool may declare no more than {ARTIFACT_HASHES_MAX_COUNT} artifact hashes."
+        ));
+    }
+    for (i, hash) in hashes.iter().enumerate() {
+        if hashes[..i]
+            .iter()
+            .any(|h| h.artifact == hash.artifact && h.algorithm == hash.algorithm)
+        {
+            return ValidateCallbackResult::Invalid(format!(
+                "Duplicate {:?} hash for artifact {:?}.",
+                hash.algorithm, hash.artifact
+            ));
+        }
+        if let ValidateCallbackResult::Invalid(e) = validate_artifact(&hash.artifact) {
+            return ValidateCallbackResult::Invalid(e);
+        }
+        if !hash.artifact.supports(&hash.algorithm) {
+            return ValidateCallbackResult::Invalid(format!(
+                "{:?} hashes can only be declared for DNA artifacts.",
+                hash.algorithm
+            ));
+        }
+        if let ValidateCallbackResult::Invalid(e) = validate_digest(&hash.algorithm, &hash.digest)
+        {
+            return ValidateCallbackResult::Invalid(e);
+        }
+    }
+    ValidateCallbackResult::Valid
+}
+
+fn validate_artifact(artifact: &Artifact) -> ValidateCallbackResult {
+    match artifact {
+        Artifact::Dna { role_name } => validate_name(role_name, "Role name", ROLE_NAME_MAX_LENGTH),
+        Artifact::Zome {
+            role_name,
+            zome_name,
+        } => {
+            if let ValidateCallbackResult::Invalid(e) =
+                validate_name(role_name, "Role name", ROLE_NAME_MAX_LENGTH)
+            {
+                return ValidateCallbackResult::Invalid(e);
+            }
+            validate_name(zome_name, "Zome name", ZOME_NAME_MAX_LENGTH)
+        }
+        _ => ValidateCallbackResult::Valid,
+    }
+}
+
+fn validate_name(name: &str, field: &str, max_length: usize) -> ValidateCallbackResult {
+    if name.is_empty() {
+        return ValidateCallbackResult::Invalid(format!("{field} may not be empty."));
+    }
+    if name.chars().count() > max_length {
+        return ValidateCallbackResult::Invalid(format!(
+            "{field} may be no longer than {max_length} characters."
+        ));
+    }
+    ValidateCallbackResult::Valid
+}
+
+pub(crate) fn validate_digest(algorithm: &HashAlgorithm, digest: &str) -> ValidateCallbackResult {
+    match algorithm.hex_digest_length() {
+        Some(length) => {
+            if digest.len() != length {
+                return ValidateCallbackResult::Invalid(format!(
+                    "{algorithm:?} digest must be {length} hex characters long."
+                ));
+            }
+            if !digest
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+            {
+                return ValidateCallbackResult::Invalid(format!(
+                    "{algorithm:?} digest must be a lowercase hex string."
+                ));
+            }
+        }
+        None => {
+            if digest.len() != DNA_HASH_B64_LENGTH || !digest.starts_with(DNA_HASH_B64_PREFIX) {
+                return ValidateCallbackResult::Invalid(format!(
+                    "{algorithm:?} digest must be a base64 encoded DnaHash starting with {DNA_HASH_B64_PREFIX}."
+                ));
+            }
+            if !digest
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                return ValidateCallbackResult::Invalid(format!(
+                    "{algorithm:?} digest contains invalid characters."
+                ));
+            }
+        }
+    }
+    ValidateCallbackResult::Valid
+}
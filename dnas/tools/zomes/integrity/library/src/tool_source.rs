@@ -0,0 +1,162 @@
+use hdi::prelude::*;
+
+use crate::{FileManifest, HashAlgorithm, ToolHashes};
+
+/// Where to get a Tool from.
+///
+/// Tool entries published before sources were typed carry a free-form JSON string. Those
+/// still deserialize into the `Legacy` variant so that existing records remain readable,
+/// but new Tool entries and updates must use one of the versioned variants.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum ToolSource {
+    V1(ToolSourceV1),
+    Legacy(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type")]
+pub enum ToolSourceV1 {
+    /// A .webhapp file that can be downloaded from the given URL
+    WebHappUrl { url: String },
+    /// A content addressed file, e.g. on IPFS
+    Cid {
+        cid: String,
+        gateway: Option<String>, // Optional URL of a gateway to fetch the content from
+    },
+    /// A release asset attached to a tag of a git repository
+    GitTag {
+        repository: String, // URL of the repository
+        tag: String,
+        asset: String, // File name of the .webhapp asset of the release
+    },
+    /// A .webhapp file stored on the DHT of this DNA
+    DhtArtifact { manifest_hash: EntryHash },
+}
+
+const URL_MAX_LENGTH: usize = 2_000;
+const CID_MIN_LENGTH: usize = 32;
+const CID_MAX_LENGTH: usize = 200;
+const GIT_TAG_MAX_LENGTH: usize = 250;
+const ASSET_MAX_LENGTH: usize = 250;
+
+/// Rules:
+/// 1. Legacy (untyped) sources may not be used anymore for new Tool entries or updates
+/// 2. URLs must be http(s) URLs of bounded length
+/// 3. CIDs must be alphanumeric and of plausible length
+/// 4. Git tags and asset names must be non-empty and may not contain whitespace
+/// 5. Artifacts stored on the DHT must refer to a FileManifest, see
+///    `validate_tool_source_manifest`
+pub fn validate_tool_source(source: &ToolSource) -> ValidateCallbackResult {
+    let source = match source {
+        ToolSource::V1(source) => source,
+        ToolSource::Legacy(_) => {
+            return ValidateCallbackResult::Invalid(
+                "Untyped Tool sources are no longer supported. Use one of the versioned source types."
+                    .into(),
+            )
+        }
+    };
+    match source {
+        ToolSourceV1::WebHappUrl { url } => validate_url(url, "WebHapp URL"),
+        ToolSourceV1::Cid { cid, gateway } => {
+            let length = cid.chars().count();
+            if !(CID_MIN_LENGTH..=CID_MAX_LENGTH).contains(&length) {
+                return ValidateCallbackResult::Invalid(format!(
+                    "CID must be between {CID_MIN_LENGTH} and {CID_MAX_LENGTH} characters long."
+                ));
+            }
+            if !cid.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return ValidateCallbackResult::Invalid(
+                    "CID may only contain alphanumeric characters.".into(),
+                );
+            }
+            match gateway {
+                Some(gateway) => validate_url(gateway, "Gateway URL"),
+                None => ValidateCallbackResult::Valid,
+            }
+        }
+        ToolSourceV1::GitTag {
+            repository,
+            tag,
+            asset,
+        } => {
+            if let ValidateCallbackResult::Invalid(e) = validate_url(repository, "Repository URL")
+            {
+                return ValidateCallbackResult::Invalid(e);
+            }
+            if let ValidateCallbackResult::Invalid(e) =
+                validate_identifier(tag, "Git tag", GIT_TAG_MAX_LENGTH)
+            {
+                return ValidateCallbackResult::Invalid(e);
+            }
+            validate_identifier(asset, "Asset name", ASSET_MAX_LENGTH)
+        }
+        ToolSourceV1::DhtArtifact { .. } => ValidateCallbackResult::Valid,
+    }
+}
+
+/// The manifest of an artifact stored on the DHT needs to be fetched, which is why this is
+/// not part of `validate_tool_source`. The Tool must declare the Sha256 digest of the file in
+/// its hashes, so that downloaders can verify the chunks the manifest lists.
+pub fn validate_tool_source_manifest(
+    source: &ToolSource,
+    hashes: &ToolHashes,
+) -> ExternResult<ValidateCallbackResult> {
+    let ToolSource::V1(ToolSourceV1::DhtArtifact { manifest_hash }) = source else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    let entry = must_get_entry(manifest_hash.clone())?;
+    let Ok(file_manifest) = FileManifest::try_from(entry.content) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The source of the Tool is not a FileManifest entry.".into(),
+        ));
+    };
+    let declares_digest = match hashes {
+        ToolHashes::V1(hashes) => hashes.iter().any(|hash| {
+            hash.algorithm == HashAlgorithm::Sha256 && hash.digest == file_manifest.sha256
+        }),
+        ToolHashes::Legacy(_) => false,
+    };
+    if !declares_digest {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A Tool whose source is a FileManifest must declare the Sha256 digest of the file in its hashes.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub(crate) fn validate_url(url: &str, field: &str) -> ValidateCallbackResult {
+    if url.chars().count() > URL_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "{field} may be no longer than {URL_MAX_LENGTH} characters."
+        ));
+    }
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return ValidateCallbackResult::Invalid(format!(
+            "{field} must start with https:// or http://"
+        ));
+    };
+    if rest.is_empty() || rest.chars().any(char::is_whitespace) {
+        return ValidateCallbackResult::Invalid(format!("{field} is not a valid URL."));
+    }
+    ValidateCallbackResult::Valid
+}
+
+fn validate_identifier(value: &str, field: &str, max_length: usize) -> ValidateCallbackResult {
+    if value.is_empty() {
+        return ValidateCallbackResult::Invalid(format!("{field} may not be empty."));
+    }
+    if value.chars().count() > max_length {
+        return ValidateCallbackResult::Invalid(format!(
+            "{field} may be no longer than {max_length} characters."
+        ));
+    }
+    if value.chars().any(char::is_whitespace) {
+        return ValidateCallbackResult::Invalid(format!("{field} may not contain whitespace."));
+    }
+    ValidateCallbackResult::Valid
+}
@@ -0,0 +1,198 @@
+use hdi::prelude::*;
+
+use crate::{is_developer_collective_owner, original_action_hash, Tool};
+
+const MAX_TAGS: usize = 10;
+const TAG_MAX_LENGTH: usize = 32;
+
+/// Tags categorize Tools, e.g. `games` or `dev-tools`. They consist of lowercase ASCII letters,
+/// digits and dashes so that they can be used as path components and compared exactly.
+pub fn validate_tool_tags(tags: &[String]) -> ValidateCallbackResult {
+    if tags.len() > MAX_TAGS {
+        return ValidateCallbackResult::Invalid(format!(
+            "A Tool may have no more than {MAX_TAGS} tags."
+        ));
+    }
+    for (index, tag) in tags.iter().enumerate() {
+        if let ValidateCallbackResult::Invalid(e) = validate_tag(tag) {
+            return ValidateCallbackResult::Invalid(e);
+        }
+        if tags[..index].contains(tag) {
+            return ValidateCallbackResult::Invalid(format!("Tag '{tag}' is listed twice."));
+        }
+    }
+    ValidateCallbackResult::Valid
+}
+
+fn validate_tag(tag: &str) -> ValidateCallbackResult {
+    if tag.is_empty() || tag.len() > TAG_MAX_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "Tags must be between 1 and {TAG_MAX_LENGTH} characters long."
+        ));
+    }
+    if !tag
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return ValidateCallbackResult::Invalid(format!(
+            "Tag '{tag}' may only contain lowercase letters, digits and dashes."
+        ));
+    }
+    ValidateCallbackResult::Valid
+}
+
+/// Gets the path that Tools with the tag are linked from
+pub fn tag_path(tag: &str) -> Path {
+    Path::from(format!("tags.{tag}"))
+}
+
+/// The tag of a TagToTools link contains the raw action hash of the Tool revision that has the
+/// tag, followed by the tag itself. The link itself points to the original Tool.
+pub fn tag_to_tools_link_tag(tool_hash: &ActionHash, tag: &str) -> LinkTag {
+    let mut link_tag = tool_hash.get_raw_39().to_vec();
+    link_tag.extend_from_slice(tag.as_bytes());
+    LinkTag::new(link_tag)
+}
+
+/// Parses the Tool revision action hash and the tag from the tag of a TagToTools link
+pub fn parse_tag_to_tools_link_tag(link_tag: &LinkTag) -> Result<(ActionHash, String), String> {
+    if link_tag.0.len() < 39 {
+        return Err("Link tag is too short to contain an action hash.".into());
+    }
+    let (tool_hash, tag) = link_tag.0.split_at(39);
+    let tool_hash = ActionHash::from_raw_39(tool_hash.to_vec()).map_err(|e| {
+        format!("Link tag does not contain a valid action hash. Conversion failed with error: {e}")
+    })?;
+    let tag = String::from_utf8(tag.to_vec())
+        .map_err(|_| String::from("Link tag does not contain a valid tag."))?;
+    Ok((tool_hash, tag))
+}
+
+/// Rules
+/// 1. The link tag must contain the action hash of a Tool revision and one of its tags
+/// 2. The link must point away from the path of that tag
+/// 3. The link must point to the original Tool of the revision
+/// 4. Only the agent that created the revision can link the Tool from its tags
+pub fn validate_create_link_tag_to_tools(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    link_tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (tool_hash, tag) = match parse_tag_to_tools_link_tag(&link_tag) {
+        Ok(parsed) => parsed,
+        Err(e) => return Ok(ValidateCallbackResult::Invalid(e)),
+    };
+    if base_address.into_entry_hash() != Some(tag_path(&tag).path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "TagToTools link is not pointing away from the path of its tag.".into(),
+        ));
+    }
+    let record = must_get_valid_record(tool_hash.clone())?;
+    let tool: crate::Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Link tag must reference a Tool entry".to_string()
+        )))?;
+    if !tool.tags.contains(&tag) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The Tool revision in the link tag does not have the tag.".into(),
+        ));
+    }
+    if target_address.into_action_hash() != Some(original_action_hash(tool_hash)?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "TagToTools links must point to the original Tool.".into(),
+        ));
+    }
+    if &action.author != record.action().author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that created a Tool revision can link the Tool from its tags.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the agent that created the link or an owner of the Tool's DeveloperCollective can
+///    delete the link
+pub fn validate_delete_link_tag_to_tools(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let original_tool_hash =
+        target
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(original_tool_hash)?;
+    let tool: Tool = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a Tool entry".to_string()
+        )))?;
+    if is_developer_collective_owner(
+        &action.author,
+        &tool.developer_collective,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "TagToTools links can only be deleted by the agent that created the link or owners of the DeveloperCollective.".into(),
+    ))
+}
+
+/// Rules
+/// 1. The link must point away from the all_tags anchor
+/// 2. The link tag must contain a valid tag and the link must point to the path of that tag
+pub fn validate_create_link_all_tags(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    link_tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address.into_entry_hash() != Some(Path::from("all_tags").path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllTags link is not pointing away from the all_tags anchor.".into(),
+        ));
+    }
+    let Ok(tag) = String::from_utf8(link_tag.0) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not contain a valid tag.".into(),
+        ));
+    };
+    if let ValidateCallbackResult::Invalid(e) = validate_tag(&tag) {
+        return Ok(ValidateCallbackResult::Invalid(e));
+    }
+    if target_address.into_entry_hash() != Some(tag_path(&tag).path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllTags links must point to the path of the tag in their link tag.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Cannot be deleted
+pub fn validate_delete_link_all_tags(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Links from the all_tags anchor cannot be deleted.",
+    )))
+}
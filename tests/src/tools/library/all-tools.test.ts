@@ -0,0 +1,116 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record, Link } from "@holochain/client";
+import { decode } from "@msgpack/msgpack";
+
+import {
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+  sampleToolUpdate,
+} from "./common.js";
+
+test("create Tools and get all tools", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Bob gets all tools
+    let collectionOutput: Link[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_tool_links",
+      payload: null,
+    });
+    assert.equal(collectionOutput.length, 0);
+
+    // Alice and Bob each publish a Tool in their own DeveloperCollective
+    const alicesCollective: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const alicesTool: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: alicesCollective.signed_action.hashed.hash,
+        permission_hash: alicesCollective.signed_action.hashed.hash,
+      })
+    );
+    const bobsCollective: Record = await createDeveloperCollective(
+      bob.cells[0]
+    );
+    await createTool(
+      bob.cells[0],
+      await sampleTool(bob.cells[0], {
+        developer_collective: bobsCollective.signed_action.hashed.hash,
+        permission_hash: bobsCollective.signed_action.hashed.hash,
+      })
+    );
+
+    // Alice updates her Tool
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: alicesTool.signed_action.hashed.hash,
+        previous_tool_hash: alicesTool.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: alicesCollective.signed_action.hashed.hash,
+          version: "0.2.0",
+        }),
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob gets all tools again
+    const allTools: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_tools",
+      payload: null,
+    });
+    assert.equal(allTools.length, 2);
+
+    // The latest revision of Alice's Tool is returned
+    const allLatestTools: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_latest_tools",
+      payload: null,
+    });
+    assert.equal(allLatestTools.length, 2);
+    const versions = allLatestTools.map(
+      (record) => (decode((record.entry as any).Present.entry) as any).version
+    );
+    assert.include(versions, "0.2.0");
+
+    // Alice deletes her Tool
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "delete_tool",
+      payload: alicesTool.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    collectionOutput = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_tool_links",
+      payload: null,
+    });
+    assert.equal(collectionOutput.length, 1);
+  });
+});
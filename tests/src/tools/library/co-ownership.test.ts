@@ -0,0 +1,419 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record, AgentPubKey, ActionHash } from "@holochain/client";
+
+import {
+  createContributorPermission,
+  createDeveloperCollective,
+  createTool,
+  sampleDeveloperCollective,
+  sampleTool,
+} from "./common.js";
+
+test("co-owners can update a DeveloperCollective and issue permissions once they accepted", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 3 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob, carol] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Alice appoints Bob as co-owner
+    const coOwnershipRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_co_owner",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: bob.agentPubKey,
+      },
+    });
+    assert.ok(coOwnershipRecord);
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    const owners: AgentPubKey[] = await carol.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_owners_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(owners.length, 2);
+    assert.deepEqual(owners[0], alice.agentPubKey);
+    assert.deepEqual(owners[1], bob.agentPubKey);
+
+    const updateInput = {
+      original_developer_collective_hash: collectiveHash,
+      previous_developer_collective_hash: collectiveHash,
+      updated_developer_collective: await sampleDeveloperCollective(
+        bob.cells[0],
+        { name: "Renamed by co-owner" }
+      ),
+    };
+
+    // Bob cannot update the DeveloperCollective before accepting the co-ownership
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "update_developer_collective",
+        payload: updateInput,
+      });
+      assert.fail("Bob should not be able to update the DeveloperCollective yet.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes(
+            "Developer collective entry can only be updated by an owner of the collective."
+          )
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Bob accepts the co-ownership
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: coOwnershipRecord.signed_action.hashed.hash,
+    });
+
+    // Now Bob can update the DeveloperCollective
+    const updatedRecord: Record = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_developer_collective",
+      payload: updateInput,
+    });
+    assert.ok(updatedRecord);
+
+    // And issue contributor permissions for it
+    const permissionRecord: Record = await createContributorPermission(
+      bob,
+      carol,
+      {
+        for_agent: carol.agentPubKey,
+        for_collective: collectiveHash,
+        expiry: undefined,
+      }
+    );
+    assert.ok(permissionRecord);
+
+    // Bob's permission for the collective is his CoOwnership
+    const bobsPermission = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_permission",
+      payload: collectiveHash,
+    });
+    assert.deepEqual(bobsPermission, coOwnershipRecord.signed_action.hashed.hash);
+
+    // Carol cannot appoint herself as co-owner
+    try {
+      await carol.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "add_co_owner",
+        payload: {
+          developer_collective_hash: collectiveHash,
+          agent: carol.agentPubKey,
+        },
+      });
+      assert.fail("Carol should not be able to appoint co-owners.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("Only owners of the developer collective can appoint co-owners.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("co-owners can be removed by owners or leave themselves", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 3 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob, carol] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective and appoints Bob and Carol as co-owners
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const bobsCoOwnership: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_co_owner",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: bob.agentPubKey,
+      },
+    });
+    const carolsCoOwnership: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_co_owner",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: carol.agentPubKey,
+      },
+    });
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: bobsCoOwnership.signed_action.hashed.hash,
+    });
+    await carol.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: carolsCoOwnership.signed_action.hashed.hash,
+    });
+
+    // Alice removes Bob
+    const bobsRevocationHash: ActionHash = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "remove_co_owner",
+      payload: bobsCoOwnership.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    let owners: AgentPubKey[] = await carol.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_owners_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(owners.length, 2);
+    assert.deepEqual(owners[0], alice.agentPubKey);
+    assert.deepEqual(owners[1], carol.agentPubKey);
+
+    // Validation rejects Tools that act with the revocation of Bob's CoOwnership
+    try {
+      await createTool(
+        bob.cells[0],
+        await sampleTool(bob.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: bobsRevocationHash,
+        })
+      );
+      assert.fail("Bob should not be able to act with a revoked CoOwnership.");
+    } catch (e) {
+      if (!e.toString().includes("CoOwnership has been revoked.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Carol leaves the collective herself
+    await carol.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "remove_co_owner",
+      payload: carolsCoOwnership.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    owners = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_owners_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(owners.length, 1);
+    assert.deepEqual(owners[0], alice.agentPubKey);
+
+    // Carol can no longer update the DeveloperCollective
+    try {
+      await carol.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "update_developer_collective",
+        payload: {
+          original_developer_collective_hash: collectiveHash,
+          previous_developer_collective_hash: collectiveHash,
+          updated_developer_collective: await sampleDeveloperCollective(
+            carol.cells[0],
+            { name: "Renamed by former co-owner" }
+          ),
+        },
+      });
+      assert.fail("Carol should no longer be able to update the DeveloperCollective.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes(
+            "Developer collective entry can only be updated by an owner of the collective."
+          )
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("co-owners removed by another owner can no longer act as owners", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 3 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob, carol] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective with a Tool and appoints Bob and Carol as co-owners
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    const bobsCoOwnership: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_co_owner",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: bob.agentPubKey,
+      },
+    });
+    const carolsCoOwnership: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_co_owner",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: carol.agentPubKey,
+      },
+    });
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: bobsCoOwnership.signed_action.hashed.hash,
+    });
+    await carol.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: carolsCoOwnership.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    // Carol removes Bob
+    await carol.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "remove_co_owner",
+      payload: bobsCoOwnership.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    // Bob has acknowledged the revocation, so validation no longer considers him an owner
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "update_developer_collective",
+        payload: {
+          original_developer_collective_hash: collectiveHash,
+          previous_developer_collective_hash: collectiveHash,
+          updated_developer_collective: await sampleDeveloperCollective(
+            bob.cells[0],
+            { name: "Renamed by removed co-owner" }
+          ),
+        },
+      });
+      assert.fail("Bob should no longer be able to update the DeveloperCollective.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes(
+            "Developer collective entry can only be updated by an owner of the collective."
+          )
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "delete_tool",
+        payload: toolRecord.signed_action.hashed.hash,
+      });
+      assert.fail("Bob should no longer be able to delete Tools of the DeveloperCollective.");
+    } catch (e) {
+      if (!e.toString().includes("Only owners of the DeveloperCollective or the creator of")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Accepting the revoked CoOwnership again doesn't restore Bob's ownership
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: bobsCoOwnership.signed_action.hashed.hash,
+    });
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "delete_tool",
+        payload: toolRecord.signed_action.hashed.hash,
+      });
+      assert.fail("Bob should not regain ownership with a revoked CoOwnership.");
+    } catch (e) {
+      if (!e.toString().includes("Only owners of the DeveloperCollective or the creator of")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
@@ -1,14 +1,40 @@
-import { CallableCell } from "@holochain/tryorama";
-import { Record, fakeActionHash, fakeAgentPubKey } from "@holochain/client";
+import { CallableCell, Player, dhtSync } from "@holochain/tryorama";
+import {
+  EntryHash,
+  Record,
+  fakeActionHash,
+  fakeAgentPubKey,
+} from "@holochain/client";
+
+export const SAMPLE_ICON = {
+  mime_type: "image/png",
+  data: Uint8Array.from(
+    atob(
+      "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg=="
+    ),
+    (c) => c.charCodeAt(0)
+  ),
+};
+
+export async function createIcon(
+  cell: CallableCell,
+  icon = undefined
+): Promise<EntryHash> {
+  return cell.callZome({
+    zome_name: "library",
+    fn_name: "create_icon",
+    payload: icon || SAMPLE_ICON,
+  });
+}
 
 export async function sampleCurator(cell: CallableCell, partialCurator = {}) {
   return {
     ...{
-      name: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      name: "Dummy Curator",
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      website: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      email: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      icon: await createIcon(cell),
+      website: "https://example.org",
+      email: "curator@example.org",
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
     },
     ...partialCurator,
@@ -36,7 +62,7 @@ export async function sampleDeveloperCollective(
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       website: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       contact: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      icon: await createIcon(cell),
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
     },
     ...partialDeveloperCollective,
@@ -54,9 +80,9 @@ export async function createDeveloperCollective(
   });
 }
 
-export async function sampleContributorPermission(
+export async function sampleContributorInvitation(
   cell: CallableCell,
-  partialContributorPermission = {}
+  partialContributorInvitation = {}
 ) {
   return {
     ...{
@@ -64,19 +90,43 @@ export async function sampleContributorPermission(
         .hashed.hash,
       for_agent: await fakeAgentPubKey(),
       expiry: 1674053334548000,
+      role: "Maintainer",
+      tools: null,
     },
-    ...partialContributorPermission,
+    ...partialContributorInvitation,
   };
 }
 
-export async function createContributorPermission(
+export async function createContributorInvitation(
   cell: CallableCell,
-  contributorPermission = undefined
+  contributorInvitation = undefined
 ): Promise<Record> {
   return cell.callZome({
     zome_name: "library",
-    fn_name: "create_contributor_permission",
-    payload: contributorPermission || (await sampleContributorPermission(cell)),
+    fn_name: "invite_contributor",
+    payload: contributorInvitation || (await sampleContributorInvitation(cell)),
+  });
+}
+
+// The owner invites the invitee, who then accepts the invitation and thereby
+// creates the ContributorPermission
+export async function createContributorPermission(
+  owner: Player,
+  invitee: Player,
+  partialContributorInvitation = {}
+): Promise<Record> {
+  const invitationRecord = await createContributorInvitation(
+    owner.cells[0],
+    await sampleContributorInvitation(owner.cells[0], {
+      ...partialContributorInvitation,
+      for_agent: invitee.agentPubKey,
+    })
+  );
+  await dhtSync([owner, invitee], owner.cells[0].cell_id[0]);
+  return invitee.cells[0].callZome({
+    zome_name: "library",
+    fn_name: "accept_invitation",
+    payload: invitationRecord.signed_action.hashed.hash,
   });
 }
 
@@ -89,32 +139,55 @@ export async function sampleTool(cell: CallableCell, partialTool = {}) {
       title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      version: "0.1.0",
+      icon: await createIcon(cell),
+      source: {
+        type: "WebHappUrl",
+        url: "https://example.org/releases/tool.webhapp",
+      },
+      hashes: [
+        {
+          artifact: { type: "WebHapp" },
+          algorithm: "Sha256",
+          digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+        },
+      ],
       changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      tags: [],
     },
     ...partialTool,
   };
 }
 
-export async function sampleToolUpdate(partialTool = {}) {
+export async function sampleToolUpdate(
+  cell: CallableCell,
+  partialTool = {}
+) {
   return {
     ...{
       permission_hash: await fakeActionHash(),
       title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      version: "0.1.1",
+      icon: await createIcon(cell),
+      source: {
+        type: "WebHappUrl",
+        url: "https://example.org/releases/tool.webhapp",
+      },
+      hashes: [
+        {
+          artifact: { type: "WebHapp" },
+          algorithm: "Sha256",
+          digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+        },
+      ],
       changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      tags: [],
     },
     ...partialTool,
   };
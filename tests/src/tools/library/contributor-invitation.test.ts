@@ -0,0 +1,290 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { ActionHash, AppSignal, Record, Signal, SignalType } from "@holochain/client";
+
+import {
+  createContributorInvitation,
+  createDeveloperCollective,
+  sampleContributorInvitation,
+} from "./common.js";
+
+test("invitations are pending until the invitee declines or accepts them", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective and invites Bob twice
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const invitation = await sampleContributorInvitation(alice.cells[0], {
+      for_agent: bob.agentPubKey,
+      for_collective: collectiveHash,
+      expiry: undefined,
+    });
+    const firstInvitation: Record = await createContributorInvitation(
+      alice.cells[0],
+      invitation
+    );
+    const secondInvitation: Record = await createContributorInvitation(
+      alice.cells[0],
+      invitation
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    let pendingInvitations: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_pending_invitations",
+      payload: null,
+    });
+    assert.equal(pendingInvitations.length, 2);
+
+    // Alice cannot accept an invitation for Bob
+    try {
+      await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "accept_invitation",
+        payload: firstInvitation.signed_action.hashed.hash,
+      });
+      assert.fail("Only Bob should be able to accept the invitation.");
+    } catch (e) {
+      if (
+        !e.toString().includes("Only the invited agent can accept an invitation")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Bob declines the first invitation and accepts the second one
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "decline_invitation",
+      payload: firstInvitation.signed_action.hashed.hash,
+    });
+    const permissionRecord: Record = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_invitation",
+      payload: secondInvitation.signed_action.hashed.hash,
+    });
+    assert.ok(permissionRecord);
+
+    pendingInvitations = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_pending_invitations",
+      payload: null,
+    });
+    assert.equal(pendingInvitations.length, 0);
+
+    // A declined invitation can no longer be accepted
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "accept_invitation",
+        payload: firstInvitation.signed_action.hashed.hash,
+      });
+      assert.fail("A declined invitation should not be accepted.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("ContributorInvitation has been declined or withdrawn")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // An invitation can only be accepted once
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "accept_invitation",
+        payload: secondInvitation.signed_action.hashed.hash,
+      });
+      assert.fail("An accepted invitation should not be accepted again.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("The contributor invitation has already been accepted or declined.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob's permission for the collective is the one he accepted
+    const bobsPermission = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_permission",
+      payload: collectiveHash,
+    });
+    assert.deepEqual(bobsPermission, permissionRecord.signed_action.hashed.hash);
+  });
+});
+
+test("only the invitee can decline an invitation and owners can withdraw it", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective and invites Bob
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const invitationRecord: Record = await createContributorInvitation(
+      alice.cells[0],
+      await sampleContributorInvitation(alice.cells[0], {
+        for_agent: bob.agentPubKey,
+        for_collective: collectiveHash,
+        expiry: undefined,
+      })
+    );
+    const invitationHash = invitationRecord.signed_action.hashed.hash;
+
+    // Alice cannot decline the invitation on Bob's behalf
+    try {
+      await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "decline_invitation",
+        payload: invitationHash,
+      });
+      assert.fail("Only Bob should be able to decline the invitation.");
+    } catch (e) {
+      if (!e.toString().includes("Only the invited agent can decline an invitation.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Alice withdraws the invitation instead
+    const revocationHash = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "withdraw_invitation",
+      payload: invitationHash,
+    });
+    assert.ok(revocationHash);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const pendingInvitations: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_pending_invitations",
+      payload: null,
+    });
+    assert.equal(pendingInvitations.length, 0);
+
+    // A withdrawn invitation can no longer be accepted
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "accept_invitation",
+        payload: invitationHash,
+      });
+      assert.fail("A withdrawn invitation should not be accepted.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("ContributorInvitation has been declined or withdrawn")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("the invitee is notified about a new invitation", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Bob waits for the remote signal about the invitation
+    const invitationReceived = new Promise<ActionHash>((resolve) => {
+      bob.appWs.on("signal", (signal: Signal) => {
+        if (signal.type !== SignalType.App) return;
+        const payload = (signal.value as AppSignal).payload as any;
+        if (payload.type === "InvitationReceived") {
+          resolve(payload.contributor_invitation_hash);
+        }
+      });
+    });
+
+    // Alice creates a developer collective and invites Bob
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const invitationRecord: Record = await createContributorInvitation(
+      alice.cells[0],
+      await sampleContributorInvitation(alice.cells[0], {
+        for_agent: bob.agentPubKey,
+        for_collective: collectiveRecord.signed_action.hashed.hash,
+      })
+    );
+
+    // The signal only carries the hash of the invitation, which Bob fetches himself
+    const invitationHash = await invitationReceived;
+    assert.deepEqual(invitationHash, invitationRecord.signed_action.hashed.hash);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const pendingInvitations: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_pending_invitations",
+      payload: null,
+    });
+    assert.equal(pendingInvitations.length, 1);
+    assert.deepEqual(
+      pendingInvitations[0].signed_action.hashed.hash,
+      invitationHash
+    );
+  });
+});
@@ -1,12 +1,15 @@
 import { assert, test } from "vitest";
 
 import { runScenario, dhtSync } from "@holochain/tryorama";
-import { Record, Link, encodeHashToBase64 } from "@holochain/client";
+import { ActionHash, Record, Link, encodeHashToBase64 } from "@holochain/client";
 import { decode } from "@msgpack/msgpack";
 
 import {
   createContributorPermission,
-  sampleContributorPermission,
+  createDeveloperCollective,
+  createTool,
+  sampleContributorInvitation,
+  sampleTool,
 } from "./common.js";
 
 test("create ContributorPermission", async () => {
@@ -29,8 +32,8 @@ test("create ContributorPermission", async () => {
     // conductor of the scenario.
     await scenario.shareAllAgents();
 
-    // Alice creates a ContributorPermission
-    const record: Record = await createContributorPermission(alice.cells[0]);
+    // Alice invites Bob and Bob accepts the invitation
+    const record: Record = await createContributorPermission(alice, bob);
     assert.ok(record);
   });
 });
@@ -55,13 +58,14 @@ test("create and read ContributorPermission", async () => {
     // conductor of the scenario.
     await scenario.shareAllAgents();
 
-    const sample = await sampleContributorPermission(alice.cells[0], {
+    const sample = await sampleContributorInvitation(alice.cells[0], {
       for_agent: bob.cells[0].cell_id[1],
     });
 
-    // Alice creates a ContributorPermission for Bob
+    // Alice invites Bob and Bob accepts the invitation
     const record: Record = await createContributorPermission(
-      alice.cells[0],
+      alice,
+      bob,
       sample
     );
     assert.ok(record);
@@ -75,9 +79,18 @@ test("create and read ContributorPermission", async () => {
       fn_name: "get_contributor_permission",
       payload: record.signed_action.hashed.hash,
     });
+    const contributorPermission = decode(
+      (createReadOutput.entry as any).Present.entry
+    ) as any;
+    // The permission references the invitation it accepts
+    assert.ok(contributorPermission.invitation);
     assert.deepEqual(
-      sample,
-      decode((createReadOutput.entry as any).Present.entry) as any
+      {
+        ...sample,
+        invitation: contributorPermission.invitation,
+        renews: null,
+      },
+      contributorPermission
     );
     // Check that the contributor permission is for Bob
     assert.equal(
@@ -113,3 +126,392 @@ test("create and read ContributorPermission", async () => {
     // TODO check more zome functions like get_my_contributor_permissions etc.
   });
 });
+
+test("revoke ContributorPermission", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Alice creates a ContributorPermission for Bob that never expires
+    const permissionRecord: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_agent: bob.agentPubKey,
+        for_collective: collectiveHash,
+        expiry: undefined,
+      }
+    );
+    const permissionHash = permissionRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob may not revoke the permission himself
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "revoke_contributor_permission",
+        payload: permissionHash,
+      });
+      assert.fail("Bob should not be able to revoke a ContributorPermission.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes(
+            "Only owners of the developer collective can revoke contributor permissions for it."
+          )
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Alice revokes the permission
+    const revocationHash: ActionHash = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "revoke_contributor_permission",
+      payload: permissionHash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const revocation = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_contributor_permission_revocation",
+      payload: permissionHash,
+    });
+    assert.ok(revocation);
+
+    // Bob no longer has a permission for the collective
+    const myPermission = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_permission",
+      payload: collectiveHash,
+    });
+    assert.isNull(myPermission);
+
+    // Bob can no longer publish Tools with the revoked permission
+    try {
+      await createTool(
+        bob.cells[0],
+        await sampleTool(bob.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: permissionHash,
+        })
+      );
+      assert.fail("Bob should not be able to use a revoked ContributorPermission.");
+    } catch (e) {
+      if (!e.toString().includes("ContributorPermission has been revoked.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Validation rejects Tools that act with the revocation, the latest state of the permission
+    try {
+      await createTool(
+        bob.cells[0],
+        await sampleTool(bob.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: revocationHash,
+        })
+      );
+      assert.fail("Bob should not be able to act with a PermissionRevocation.");
+    } catch (e) {
+      if (!e.toString().includes("ContributorPermission has been revoked.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // The revoked permission cannot be revived by renewing it
+    try {
+      await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "renew_contributor_permission",
+        payload: {
+          previous_permission_hash: permissionHash,
+          expiry: Date.now() * 1000 + 30 * 24 * 60 * 60 * 1000 * 1000,
+        },
+      });
+      assert.fail("Alice should not be able to renew a revoked ContributorPermission.");
+    } catch (e) {
+      if (!e.toString().includes("ContributorPermission has been revoked.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("renew ContributorPermission", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Bob gets a ContributorPermission that expires in a day
+    const oneDay = 24 * 60 * 60 * 1_000_000;
+    const permissionRecord: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_collective: collectiveHash,
+        expiry: Date.now() * 1000 + oneDay,
+      }
+    );
+    const permissionHash = permissionRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    let expiringPermissions: Record[] = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_expiring_permissions",
+      payload: { developer_collective_hash: collectiveHash, within_days: 7 },
+    });
+    assert.equal(expiringPermissions.length, 1);
+    assert.deepEqual(
+      expiringPermissions[0].signed_action.hashed.hash,
+      permissionHash
+    );
+
+    // Alice renews the permission for another 30 days
+    const renewalRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "renew_contributor_permission",
+      payload: {
+        previous_permission_hash: permissionHash,
+        expiry: Date.now() * 1000 + 30 * oneDay,
+      },
+    });
+    const renewalHash = renewalRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const latestPermission: Record = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_latest_contributor_permission",
+      payload: permissionHash,
+    });
+    assert.deepEqual(latestPermission.signed_action.hashed.hash, renewalHash);
+
+    // Bob's current permission is the renewal
+    const myPermission = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_permission",
+      payload: collectiveHash,
+    });
+    assert.deepEqual(myPermission, renewalHash);
+
+    expiringPermissions = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_expiring_permissions",
+      payload: { developer_collective_hash: collectiveHash, within_days: 7 },
+    });
+    assert.equal(expiringPermissions.length, 0);
+  });
+});
+
+test("revoking a renewal revokes the permissions it renews", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Bob gets a ContributorPermission that expires in a day
+    const oneDay = 24 * 60 * 60 * 1_000_000;
+    const permissionRecord: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_collective: collectiveHash,
+        expiry: Date.now() * 1000 + oneDay,
+      }
+    );
+    const permissionHash = permissionRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Alice renews the permission and then revokes the renewal
+    const renewalRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "renew_contributor_permission",
+      payload: {
+        previous_permission_hash: permissionHash,
+        expiry: Date.now() * 1000 + 30 * oneDay,
+      },
+    });
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "revoke_contributor_permission",
+      payload: renewalRecord.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // The original permission has no revocation of its own, so the coordinator lets Bob act
+    // with it, but Bob has acknowledged the revocation of the renewal and validation rejects it
+    try {
+      await createTool(
+        bob.cells[0],
+        await sampleTool(bob.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: permissionHash,
+        })
+      );
+      assert.fail(
+        "Bob should not be able to act with a permission whose renewal has been revoked."
+      );
+    } catch (e) {
+      if (!e.toString().includes("ContributorPermission has been revoked.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("get permission status of owners, contributors and other agents", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Bob gets a ContributorPermission that expires in a day
+    const expiry = Date.now() * 1000 + 24 * 60 * 60 * 1_000_000;
+    const permissionRecord: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_collective: collectiveHash,
+        expiry,
+        role: "Publisher",
+      }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const aliceStatus = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_agent_permission_status",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: alice.agentPubKey,
+        at: null,
+      },
+    });
+    assert.equal(aliceStatus.access_level, "Owner");
+    assert.deepEqual(aliceStatus.permission_hash, collectiveHash);
+
+    const bobStatus = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_agent_permission_status",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: bob.agentPubKey,
+        at: null,
+      },
+    });
+    assert.equal(bobStatus.access_level, "Contributor");
+    assert.deepEqual(
+      bobStatus.permission_hash,
+      permissionRecord.signed_action.hashed.hash
+    );
+    assert.equal(bobStatus.role, "Publisher");
+    assert.equal(bobStatus.expiry, expiry);
+    assert.ok(bobStatus.time_remaining > 0);
+    assert.equal(bobStatus.grants.length, 1);
+    assert.equal(bobStatus.grants[0].state, "Active");
+
+    // Evaluated after the expiry, Bob's permission is no longer active
+    const laterBobStatus = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_agent_permission_status",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: bob.agentPubKey,
+        at: expiry + 1,
+      },
+    });
+    assert.equal(laterBobStatus.access_level, "NoAccess");
+    assert.equal(laterBobStatus.permission_hash, null);
+    assert.equal(laterBobStatus.grants[0].state, "Expired");
+  });
+});
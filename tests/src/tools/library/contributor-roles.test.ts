@@ -0,0 +1,161 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  createContributorPermission,
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+  sampleToolUpdate,
+} from "./common.js";
+
+test("a metadata editor scoped to a Tool can only edit the metadata of that Tool", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective with two Tools
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    const toolHash = toolRecord.signed_action.hashed.hash;
+    const otherToolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    const otherToolHash = otherToolRecord.signed_action.hashed.hash;
+
+    // Alice lets Bob edit the metadata of the first Tool
+    const permissionRecord: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_agent: bob.agentPubKey,
+        for_collective: collectiveHash,
+        expiry: undefined,
+        role: "MetadataEditor",
+        tools: [toolHash],
+      }
+    );
+    const permissionHash = permissionRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob can edit the description of the Tool
+    const updatedRecord: Record = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: toolHash,
+        previous_tool_hash: toolHash,
+        updated_tool: await sampleToolUpdate(bob.cells[0], {
+          permission_hash: permissionHash,
+          version: "0.1.0",
+          description: "A translated description.",
+        }),
+      },
+    });
+    assert.ok(updatedRecord);
+
+    // Bob cannot change the source of the Tool
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "update_tool",
+        payload: {
+          original_tool_hash: toolHash,
+          previous_tool_hash: updatedRecord.signed_action.hashed.hash,
+          updated_tool: await sampleToolUpdate(bob.cells[0], {
+            permission_hash: permissionHash,
+            version: "0.1.0",
+            source: {
+              type: "WebHappUrl",
+              url: "https://example.org/releases/other.webhapp",
+            },
+          }),
+        },
+      });
+      assert.fail("A metadata editor should not be able to change the source.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("ContributorPermission only allows editing the metadata of Tools.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Bob cannot edit a Tool outside of the scope of his permission
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "update_tool",
+        payload: {
+          original_tool_hash: otherToolHash,
+          previous_tool_hash: otherToolHash,
+          updated_tool: await sampleToolUpdate(bob.cells[0], {
+            permission_hash: permissionHash,
+            version: "0.1.0",
+            description: "A translated description.",
+          }),
+        },
+      });
+      assert.fail("Tools outside of the permission scope should not be editable.");
+    } catch (e) {
+      if (
+        !e.toString().includes("ContributorPermission does not cover this Tool.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Bob cannot publish new Tools
+    try {
+      await createTool(
+        bob.cells[0],
+        await sampleTool(bob.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: permissionHash,
+        })
+      );
+      assert.fail("A metadata editor should not be able to publish new Tools.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("ContributorPermission does not allow publishing new Tools.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
@@ -233,3 +233,41 @@ test("create and delete Curator", async () => {
     assert.equal(deletesForCurator.length, 1);
   });
 });
+
+test("Try to create Curators with invalid fields", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    const invalidCurators = [
+      { partialCurator: { name: "a".repeat(51) }, error: "Name may be no longer than 50 characters." },
+      { partialCurator: { email: "curator.example.org" }, error: "Email is not a valid email address." },
+      { partialCurator: { website: "example.org" }, error: "Website must start with https:// or http://" },
+    ];
+
+    for (const { partialCurator, error } of invalidCurators) {
+      let failed = false;
+      try {
+        await createCurator(alice.cells[0], await sampleCurator(alice.cells[0], partialCurator));
+      } catch (e) {
+        failed = e.toString().includes(error);
+      }
+      assert.isTrue(failed, `Creating a Curator should fail with "${error}"`);
+    }
+  });
+});
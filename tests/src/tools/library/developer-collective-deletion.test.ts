@@ -0,0 +1,210 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Link, Record } from "@holochain/client";
+import { decode } from "@msgpack/msgpack";
+
+import {
+  createContributorPermission,
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+} from "./common.js";
+
+test("a co-owner deletes a DeveloperCollective with all its index links and deprecates its Tools", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective with a Tool and makes Bob co-owner
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+        deprecation: undefined,
+      })
+    );
+    const coOwnershipRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_co_owner",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        agent: bob.agentPubKey,
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_co_ownership",
+      payload: coOwnershipRecord.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob deletes the DeveloperCollective and deprecates its Tools
+    const deleteActionHash = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "delete_developer_collective_cascade",
+      payload: {
+        original_developer_collective_hash: collectiveHash,
+        tools: {
+          type: "Deprecate",
+          deprecation: "This collective has been dissolved.",
+        },
+      },
+    });
+    assert.ok(deleteActionHash);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Neither Alice nor Bob are linked to the DeveloperCollective anymore
+    const alicesLinks: Link[] = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_developer_collective_links",
+      payload: null,
+    });
+    assert.equal(alicesLinks.length, 0);
+    const bobsLinks: Link[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_developer_collective_links",
+      payload: null,
+    });
+    assert.equal(bobsLinks.length, 0);
+
+    // The DeveloperCollective is not listed anymore
+    const allLinks: Link[] = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_developer_collective_links",
+      payload: null,
+    });
+    assert.equal(allLinks.length, 0);
+
+    // The Tool has been deprecated
+    const latestToolRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_latest_tool",
+      payload: toolRecord.signed_action.hashed.hash,
+    });
+    assert.equal(
+      (decode((latestToolRecord.entry as any).Present.entry) as any).deprecation,
+      "This collective has been dissolved."
+    );
+  });
+});
+
+test("a DeveloperCollective with Tools can only be deleted together with its Tools and permissions", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective with a Tool and Bob as contributor
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    await createContributorPermission(alice, bob, {
+      for_agent: bob.agentPubKey,
+      for_collective: collectiveHash,
+      expiry: undefined,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Alice cannot delete the DeveloperCollective while it has Tools
+    try {
+      await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "delete_developer_collective",
+        payload: collectiveHash,
+      });
+      assert.fail("A DeveloperCollective with Tools should not be deletable.");
+    } catch (e) {
+      if (!e.toString().includes("DeveloperCollective still has Tools")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Alice deletes the DeveloperCollective together with its Tools
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "delete_developer_collective_cascade",
+      payload: {
+        original_developer_collective_hash: collectiveHash,
+        tools: { type: "Delete" },
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const toolLinks: Link[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tool_links_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(toolLinks.length, 0);
+    const toolDeletes = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_deletes_for_tool",
+      payload: toolRecord.signed_action.hashed.hash,
+    });
+    assert.equal(toolDeletes.length, 1);
+
+    // Bob's permission has been revoked and is no longer linked
+    const permissionLinks: Link[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_contributor_permissions_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(permissionLinks.length, 0);
+    const bobsPermissionLinks: Link[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_contributor_permissions_for_contributor",
+      payload: bob.agentPubKey,
+    });
+    assert.equal(bobsPermissionLinks.length, 0);
+  });
+});
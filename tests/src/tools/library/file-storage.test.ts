@@ -0,0 +1,196 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { EntryHash, Record } from "@holochain/client";
+import { createHash } from "crypto";
+
+import {
+  createDeveloperCollective,
+  createIcon,
+  createTool,
+  sampleTool,
+} from "./common.js";
+
+test("store a webhapp on the DHT and download it chunk by chunk", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // A webhapp that is stored in three chunks of at most 1MB
+    const webhapp = new Uint8Array(2_500_000).map((_, i) => i % 251);
+    const digest = createHash("sha256").update(webhapp).digest("hex");
+
+    // Alice uploads the webhapp in differently sized pieces
+    const manifestHash: EntryHash = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "upload_artifact",
+      payload: {
+        name: "tool.webhapp",
+        chunks: [webhapp.slice(0, 1_500_000), webhapp.slice(1_500_000)],
+      },
+    });
+
+    // Alice publishes a Tool whose source is the uploaded webhapp
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+        source: { type: "DhtArtifact", manifest_hash: manifestHash },
+        hashes: [{ artifact: { type: "WebHapp" }, algorithm: "Sha256", digest }],
+      })
+    );
+
+    // The source of a Tool must be a FileManifest
+    try {
+      await createTool(
+        alice.cells[0],
+        await sampleTool(alice.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: collectiveHash,
+          source: {
+            type: "DhtArtifact",
+            manifest_hash: await createIcon(alice.cells[0]),
+          },
+        })
+      );
+      assert.fail("A source that is not a FileManifest should be rejected.");
+    } catch (e) {
+      if (!e.toString().includes("is not a FileManifest entry")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // The Tool must declare the digest of the file it refers to
+    try {
+      await createTool(
+        alice.cells[0],
+        await sampleTool(alice.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: collectiveHash,
+          source: { type: "DhtArtifact", manifest_hash: manifestHash },
+          hashes: [
+            {
+              artifact: { type: "WebHapp" },
+              algorithm: "Sha256",
+              digest: "0".repeat(64),
+            },
+          ],
+        })
+      );
+      assert.fail("A Tool that doesn't declare the digest should be rejected.");
+    } catch (e) {
+      if (!e.toString().includes("must declare the Sha256 digest of the file")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob downloads the webhapp
+    const manifest: any = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_artifact_manifest",
+      payload: manifestHash,
+    });
+    assert.equal(manifest.name, "tool.webhapp");
+    assert.equal(manifest.size, webhapp.length);
+    assert.equal(manifest.sha256, digest);
+    assert.equal(manifest.chunks.length, 3);
+
+    const downloaded = new Uint8Array(manifest.size);
+    let offset = 0;
+    for (const chunkHash of manifest.chunks) {
+      const chunk: any = await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "get_artifact_chunk",
+        payload: chunkHash,
+      });
+      downloaded.set(chunk.data, offset);
+      offset += chunk.data.length;
+    }
+    assert.equal(createHash("sha256").update(downloaded).digest("hex"), digest);
+
+    // Uploading the same file again results in the same manifest
+    const secondManifestHash: EntryHash = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "upload_artifact",
+      payload: { name: "tool.webhapp", chunks: [webhapp] },
+    });
+    assert.deepEqual(secondManifestHash, manifestHash);
+
+    // Files that don't fit into a single call are uploaded chunk by chunk
+    const chunkHashes: EntryHash[] = [];
+    for (let offset = 0; offset < webhapp.length; offset += 1_000_000) {
+      chunkHashes.push(
+        await bob.cells[0].callZome({
+          zome_name: "library",
+          fn_name: "upload_artifact_chunk",
+          payload: webhapp.slice(offset, offset + 1_000_000),
+        })
+      );
+    }
+    const thirdManifestHash: EntryHash = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "create_artifact_manifest",
+      payload: {
+        name: "tool.webhapp",
+        size: webhapp.length,
+        sha256: digest,
+        chunks: chunkHashes,
+      },
+    });
+    assert.deepEqual(thirdManifestHash, manifestHash);
+
+    // Every chunk but the last must be full, so a manifest that lists a shorter chunk first is
+    // rejected even though the chunks add up to the size of the file
+    const shortChunkHash: EntryHash = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "upload_artifact_chunk",
+      payload: webhapp.slice(0, 500_000),
+    });
+    const fullChunkHash: EntryHash = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "upload_artifact_chunk",
+      payload: webhapp.slice(500_000, 1_500_000),
+    });
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "create_artifact_manifest",
+        payload: {
+          name: "tool.webhapp",
+          size: 1_500_000,
+          sha256: createHash("sha256")
+            .update(webhapp.slice(0, 1_500_000))
+            .digest("hex"),
+          chunks: [shortChunkHash, fullChunkHash],
+        },
+      });
+      assert.fail("A manifest with a wrongly sized chunk should be rejected.");
+    } catch (e) {
+      if (!e.toString().includes("Every chunk of a file but the last must be exactly")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
@@ -0,0 +1,63 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { EntryHash, Record } from "@holochain/client";
+import { decode } from "@msgpack/msgpack";
+
+import { createCurator, createIcon, SAMPLE_ICON } from "./common.js";
+
+test("store identical icons once and refer to them by their hash", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Only images are accepted as icons
+    try {
+      await createIcon(alice.cells[0], {
+        mime_type: "text/html",
+        data: new TextEncoder().encode("<h1>Hi</h1>"),
+      });
+      assert.fail("An icon that is not an image should be rejected.");
+    } catch (e) {
+      if (!e.toString().includes("Icon must be of one of the following types")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Alice stores an icon and uses it for her Curator profile
+    const iconHash: EntryHash = await createIcon(alice.cells[0]);
+    const curatorRecord: Record = await createCurator(alice.cells[0]);
+    assert.deepEqual(
+      (decode((curatorRecord.entry as any).Present.entry) as any).icon,
+      iconHash
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Storing the same image again results in the same hash
+    const bobsIconHash: EntryHash = await createIcon(bob.cells[0]);
+    assert.deepEqual(bobsIconHash, iconHash);
+
+    const icon = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_icon",
+      payload: iconHash,
+    });
+    assert.deepEqual(icon, SAMPLE_ICON);
+  });
+});
@@ -0,0 +1,277 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record, AgentPubKey, Link } from "@holochain/client";
+
+import {
+  createDeveloperCollective,
+  sampleDeveloperCollective,
+} from "./common.js";
+
+test("ownership of a DeveloperCollective passes to the recipient once they accept the transfer", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Alice offers to transfer the DeveloperCollective to Bob
+    const transferRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "offer_ownership_transfer",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        to_agent: bob.agentPubKey,
+      },
+    });
+    assert.ok(transferRecord);
+    const transferHash = transferRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob sees the offer
+    const offers: Link[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_ownership_transfers_for_recipient",
+      payload: bob.agentPubKey,
+    });
+    assert.equal(offers.length, 1);
+    assert.deepEqual(offers[0].target, transferHash);
+
+    // Bob accepts the transfer
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_ownership_transfer",
+      payload: transferHash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const owners: AgentPubKey[] = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_owners_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(owners.length, 1);
+    assert.deepEqual(owners[0], bob.agentPubKey);
+
+    // Bob's permission for the collective is the accepted transfer
+    const bobsPermission = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_my_permission",
+      payload: collectiveHash,
+    });
+    assert.deepEqual(bobsPermission, transferHash);
+
+    // Bob can update the DeveloperCollective
+    const updatedRecord: Record = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_developer_collective",
+      payload: {
+        original_developer_collective_hash: collectiveHash,
+        previous_developer_collective_hash: collectiveHash,
+        updated_developer_collective: await sampleDeveloperCollective(
+          bob.cells[0],
+          { name: "Renamed by new owner" }
+        ),
+      },
+    });
+    assert.ok(updatedRecord);
+
+    // Alice is no longer an owner and cannot update it anymore
+    try {
+      await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "update_developer_collective",
+        payload: {
+          original_developer_collective_hash: collectiveHash,
+          previous_developer_collective_hash:
+            updatedRecord.signed_action.hashed.hash,
+          updated_developer_collective: await sampleDeveloperCollective(
+            alice.cells[0],
+            { name: "Renamed by previous owner" }
+          ),
+        },
+      });
+      assert.fail("Alice should not be able to update the DeveloperCollective anymore.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes(
+            "Developer collective entry can only be updated by an owner of the collective."
+          )
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("declined ownership transfers cannot be accepted and the offering owner can reclaim the collective", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a DeveloperCollective and offers to transfer it to Bob
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const transferRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "offer_ownership_transfer",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        to_agent: bob.agentPubKey,
+      },
+    });
+    const transferHash = transferRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Alice cannot decline her own offer
+    try {
+      await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "decline_ownership_transfer",
+        payload: transferHash,
+      });
+      assert.fail("Alice should not be able to decline her own offer.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("Only the recipient of an ownership transfer can decline it.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Bob declines the transfer
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "decline_ownership_transfer",
+      payload: transferHash,
+    });
+
+    // Bob cannot accept the transfer anymore
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "accept_ownership_transfer",
+        payload: transferHash,
+      });
+      assert.fail("Bob should not be able to accept a declined transfer.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("The ownership transfer has already been accepted or declined.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Alice reclaims the collective
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "reclaim_developer_collective",
+      payload: transferHash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const owners: AgentPubKey[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_owners_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(owners.length, 1);
+    assert.deepEqual(owners[0], alice.agentPubKey);
+
+    const updatedRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_developer_collective",
+      payload: {
+        original_developer_collective_hash: collectiveHash,
+        previous_developer_collective_hash: collectiveHash,
+        updated_developer_collective: await sampleDeveloperCollective(
+          alice.cells[0],
+          { name: "Renamed after reclaiming" }
+        ),
+      },
+    });
+    assert.ok(updatedRecord);
+
+    // A transfer that has been accepted cannot be declined anymore
+    const secondTransferRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "offer_ownership_transfer",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        to_agent: bob.agentPubKey,
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "accept_ownership_transfer",
+      payload: secondTransferRecord.signed_action.hashed.hash,
+    });
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "decline_ownership_transfer",
+        payload: secondTransferRecord.signed_action.hashed.hash,
+      });
+      assert.fail("Bob should not be able to decline an accepted transfer.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("The ownership transfer has already been accepted")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
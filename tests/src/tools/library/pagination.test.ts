@@ -0,0 +1,100 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import { createDeveloperCollective, createTool, sampleTool } from "./common.js";
+
+test("page through all developer collectives and the tools of a collective", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates three DeveloperCollectives and three Tools in the first one
+    const collectives: Record[] = [];
+    for (let i = 0; i < 3; i++) {
+      collectives.push(await createDeveloperCollective(alice.cells[0]));
+    }
+    const collectiveHash = collectives[0].signed_action.hashed.hash;
+    for (let i = 0; i < 3; i++) {
+      await createTool(
+        alice.cells[0],
+        await sampleTool(alice.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: collectiveHash,
+        })
+      );
+    }
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob gets the developer collectives in pages of two
+    const firstPage = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_original_developer_collectives_page",
+      payload: { limit: 2, after: undefined },
+    });
+    assert.equal(firstPage.items.length, 2);
+    assert.ok(firstPage.next);
+
+    const secondPage = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_original_developer_collectives_page",
+      payload: { limit: 2, after: firstPage.next },
+    });
+    assert.equal(secondPage.items.length, 1);
+    assert.equal(secondPage.next, undefined);
+
+    // Every developer collective is on exactly one page
+    const pagedHashes: ActionHash[] = [
+      ...firstPage.items,
+      ...secondPage.items,
+    ].map((record: Record) => record.signed_action.hashed.hash);
+    for (const collective of collectives) {
+      assert.equal(
+        pagedHashes.filter(
+          (hash) =>
+            hash.toString() ===
+            collective.signed_action.hashed.hash.toString()
+        ).length,
+        1
+      );
+    }
+
+    // Bob gets the tools of the first collective in pages of two
+    const firstToolsPage = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_original_tools_for_developer_collective_page",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        page: { limit: 2, after: undefined },
+      },
+    });
+    assert.equal(firstToolsPage.items.length, 2);
+    const secondToolsPage = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_original_tools_for_developer_collective_page",
+      payload: {
+        developer_collective_hash: collectiveHash,
+        page: { limit: 2, after: firstToolsPage.next },
+      },
+    });
+    assert.equal(secondToolsPage.items.length, 1);
+    assert.equal(secondToolsPage.next, undefined);
+  });
+});
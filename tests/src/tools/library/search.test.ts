@@ -0,0 +1,157 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+import { decode } from "@msgpack/msgpack";
+
+import {
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+  sampleToolUpdate,
+} from "./common.js";
+
+test("search tools by words of their title and subtitle", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice publishes two Tools
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const kanbanRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+        title: "Kanban Board",
+        subtitle: "Organize the tasks of your group",
+      })
+    );
+    await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+        title: "Group Calendar",
+        subtitle: "Schedule events together",
+      })
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob finds the Kanban board by a prefix of a word of its title
+    let results: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "kanb",
+    });
+    assert.equal(results.length, 1);
+    assert.deepEqual(
+      results[0].signed_action.hashed.hash,
+      kanbanRecord.signed_action.hashed.hash
+    );
+
+    // Both Tools mention groups, the one with the word in its title ranks first
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "GROUP",
+    });
+    assert.equal(results.length, 2);
+    assert.equal(
+      (decode((results[0].entry as any).Present.entry) as any).title,
+      "Group Calendar"
+    );
+
+    // All words of the query need to match
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "group kanban",
+    });
+    assert.equal(results.length, 1);
+
+    // Long words are looked up by their indexed prefix but still need to match completely
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "calendars",
+    });
+    assert.equal(results.length, 0);
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "organize",
+    });
+    assert.equal(results.length, 1);
+
+    // Alice renames the Kanban board
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: kanbanRecord.signed_action.hashed.hash,
+        previous_tool_hash: kanbanRecord.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+          title: "Task Board",
+          subtitle: "Organize the tasks of your group",
+        }),
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // The old title doesn't match anymore, the new one does
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "kanban",
+    });
+    assert.equal(results.length, 0);
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "task board",
+    });
+    assert.equal(results.length, 1);
+    assert.equal(
+      (decode((results[0].entry as any).Present.entry) as any).title,
+      "Task Board"
+    );
+
+    // Deleted Tools are not found anymore
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "delete_tool",
+      payload: kanbanRecord.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    results = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "search_tools",
+      payload: "task",
+    });
+    assert.equal(results.length, 0);
+  });
+});
@@ -0,0 +1,75 @@
+import { assert, test } from "vitest";
+import { createHash } from "crypto";
+
+import { runScenario } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+} from "./common.js";
+
+test("Verify downloaded artifact bytes against the hashes published in a Tool", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    const webhapp = new Uint8Array([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    const digest = createHash("sha256").update(webhapp).digest("hex");
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+
+    // Alice creates a Tool declaring the sha256 hash of the webhapp
+    const record: Record = await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+      developer_collective: collectiveRecord.signed_action.hashed.hash,
+      permission_hash: collectiveRecord.signed_action.hashed.hash,
+      hashes: [{ artifact: { type: "WebHapp" }, algorithm: "Sha256", digest }],
+    }));
+
+    // The webhapp passed in two chunks verifies
+    let verification: any = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "verify_tool_artifact",
+      payload: {
+        tool_hash: record.signed_action.hashed.hash,
+        artifact: { type: "WebHapp" },
+        chunks: [webhapp.slice(0, 4), webhapp.slice(4)],
+      },
+    });
+    assert.isTrue(verification.verified);
+    assert.deepEqual(verification.results, [{ type: "Match", algorithm: "Sha256", digest }]);
+
+    // Tampered bytes do not verify
+    verification = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "verify_tool_artifact",
+      payload: {
+        tool_hash: record.signed_action.hashed.hash,
+        artifact: { type: "WebHapp" },
+        chunks: [webhapp.slice(1)],
+      },
+    });
+    assert.isFalse(verification.verified);
+    assert.equal(verification.results[0].type, "Mismatch");
+    assert.equal(verification.results[0].expected, digest);
+  });
+});
@@ -0,0 +1,312 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  createContributorPermission,
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+  sampleToolUpdate,
+} from "./common.js";
+
+test("DeveloperCollectiveToTools link with a permission for another DeveloperCollective is rejected", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective with a Tool
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+
+    // Alice creates a second developer collective and gives Bob a permission for it
+    const otherCollectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const foreignPermission: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_agent: bob.agentPubKey,
+        for_collective: otherCollectiveRecord.signed_action.hashed.hash,
+        expiry: undefined,
+      }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob tries to link the Tool to the first collective with that permission
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "add_tool_for_developer_collective",
+        payload: {
+          base_developer_collective_hash: collectiveHash,
+          target_tool_hash: toolRecord.signed_action.hashed.hash,
+          permission_hash: foreignPermission.signed_action.hashed.hash,
+        },
+      });
+      assert.fail("A link with a foreign permission should be rejected.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("ContributorPermission is for the wrong DeveloperCollective.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("DeveloperCollectiveToTools link with an expired permission is rejected", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective with a Tool
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+
+    // Alice creates a ContributorPermission for Bob that has already expired
+    const expiredPermission: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_agent: bob.agentPubKey,
+        for_collective: collectiveHash,
+        expiry: 1674053334548000,
+      }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob tries to link the Tool to the collective with the expired permission
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "add_tool_for_developer_collective",
+        payload: {
+          base_developer_collective_hash: collectiveHash,
+          target_tool_hash: toolRecord.signed_action.hashed.hash,
+          permission_hash: expiredPermission.signed_action.hashed.hash,
+        },
+      });
+      assert.fail("A link with an expired permission should be rejected.");
+    } catch (e) {
+      if (!e.toString().includes("ContributorPermission has expired.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("ToolUpdates link with a foreign permission is rejected", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective with a Tool and updates it
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    const updateRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: toolRecord.signed_action.hashed.hash,
+        previous_tool_hash: toolRecord.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+        }),
+      },
+    });
+
+    // Alice creates a second developer collective and gives Bob a permission for it
+    const otherCollectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const foreignPermission: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_agent: bob.agentPubKey,
+        for_collective: otherCollectiveRecord.signed_action.hashed.hash,
+        expiry: undefined,
+      }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob tries to link the update from the Tool with that permission
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "add_tool_update",
+        payload: {
+          original_tool_hash: toolRecord.signed_action.hashed.hash,
+          updated_tool_hash: updateRecord.signed_action.hashed.hash,
+          permission_hash: foreignPermission.signed_action.hashed.hash,
+        },
+      });
+      assert.fail("A link with a foreign permission should be rejected.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("ContributorPermission is for the wrong DeveloperCollective.")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("ToolUpdates link with an expired permission is rejected", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a developer collective with a Tool and updates it
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    const updateRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: toolRecord.signed_action.hashed.hash,
+        previous_tool_hash: toolRecord.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+        }),
+      },
+    });
+
+    // Alice creates a ContributorPermission for Bob that has already expired
+    const expiredPermission: Record = await createContributorPermission(
+      alice,
+      bob,
+      {
+        for_agent: bob.agentPubKey,
+        for_collective: collectiveHash,
+        expiry: 1674053334548000,
+      }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob tries to link the update from the Tool with the expired permission
+    try {
+      await bob.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "add_tool_update",
+        payload: {
+          original_tool_hash: toolRecord.signed_action.hashed.hash,
+          updated_tool_hash: updateRecord.signed_action.hashed.hash,
+          permission_hash: expiredPermission.signed_action.hashed.hash,
+        },
+      });
+      assert.fail("A link with an expired permission should be rejected.");
+    } catch (e) {
+      if (!e.toString().includes("ContributorPermission has expired.")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
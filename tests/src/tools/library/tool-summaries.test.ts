@@ -0,0 +1,116 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  createCurator,
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+  sampleToolUpdate,
+  SAMPLE_ICON,
+} from "./common.js";
+
+test("list Tool summaries and fetch icons on demand", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice publishes a Tool, updates it and lists it as Curator
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+      })
+    );
+    const updateRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: toolRecord.signed_action.hashed.hash,
+        previous_tool_hash: toolRecord.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+          version: "0.2.0",
+          deprecation: "Use the new Tool instead.",
+        }),
+      },
+    });
+    const curatorRecord: Record = await createCurator(alice.cells[0]);
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "add_tool_for_curator",
+      payload: {
+        base_curator_hash: curatorRecord.signed_action.hashed.hash,
+        target_tool_hash: toolRecord.signed_action.hashed.hash,
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Bob gets the summary of the latest revision without the icon
+    const summaries: any[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tool_summaries_for_developer_collective",
+      payload: collectiveHash,
+    });
+    assert.equal(summaries.length, 1);
+    assert.equal(summaries[0].version, "0.2.0");
+    assert.ok(summaries[0].deprecated);
+    assert.deepEqual(
+      summaries[0].tool_hash,
+      updateRecord.signed_action.hashed.hash
+    );
+    assert.notProperty(summaries[0], "icon");
+    assert.ok(summaries[0].icon_hash);
+
+    const curatorSummaries: any[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tool_summaries_for_curator",
+      payload: curatorRecord.signed_action.hashed.hash,
+    });
+    assert.deepEqual(curatorSummaries, summaries);
+
+    const page = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_tool_summaries_page",
+      payload: { limit: 10, after: undefined },
+    });
+    assert.deepEqual(page.items, summaries);
+
+    // Bob fetches the icon separately
+    const icon = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_icon",
+      payload: summaries[0].icon_hash,
+    });
+    assert.deepEqual(icon, SAMPLE_ICON);
+    const toolIcon = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tool_icon",
+      payload: summaries[0].tool_hash,
+    });
+    assert.deepEqual(toolIcon, SAMPLE_ICON);
+  });
+});
@@ -0,0 +1,130 @@
+import { assert, test } from "vitest";
+
+import { runScenario, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  createDeveloperCollective,
+  createTool,
+  sampleTool,
+  sampleToolUpdate,
+} from "./common.js";
+
+test("tag Tools and move them between tags", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Tags must be lowercase
+    try {
+      await createTool(
+        alice.cells[0],
+        await sampleTool(alice.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: collectiveHash,
+          tags: ["Games"],
+        })
+      );
+      assert.fail("A tag with uppercase letters should be rejected.");
+    } catch (e) {
+      if (
+        !e
+          .toString()
+          .includes("may only contain lowercase letters, digits and dashes")
+      ) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // Alice publishes a game
+    const toolRecord: Record = await createTool(
+      alice.cells[0],
+      await sampleTool(alice.cells[0], {
+        developer_collective: collectiveHash,
+        permission_hash: collectiveHash,
+        tags: ["games"],
+      })
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    let games: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tools_for_tag",
+      payload: "games",
+    });
+    assert.equal(games.length, 1);
+
+    // Alice recategorizes the Tool
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: toolRecord.signed_action.hashed.hash,
+        previous_tool_hash: toolRecord.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+          tags: ["productivity", "dev-tools"],
+        }),
+      },
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    games = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tools_for_tag",
+      payload: "games",
+    });
+    assert.equal(games.length, 0);
+    const devTools: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tools_for_tag",
+      payload: "dev-tools",
+    });
+    assert.equal(devTools.length, 1);
+
+    const allTags: string[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_all_tags",
+      payload: null,
+    });
+    assert.deepEqual(allTags, ["dev-tools", "games", "productivity"]);
+
+    // Deleted Tools are removed from their tags
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "delete_tool",
+      payload: toolRecord.signed_action.hashed.hash,
+    });
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const productivity: Record[] = await bob.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tools_for_tag",
+      payload: "productivity",
+    });
+    assert.equal(productivity.length, 0);
+  });
+});
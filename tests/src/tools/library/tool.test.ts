@@ -5,11 +5,15 @@ import {
   Record,
   fakeActionHash,
 } from "@holochain/client";
+import { decode } from "@msgpack/msgpack";
 
 import {
   createContributorPermission,
   createDeveloperCollective,
+  createIcon,
   createTool,
+  sampleTool,
+  sampleToolUpdate,
 } from "./common.js";
 
 test("Create a developer collective, then create Tool as the creator of the collective", async () => {
@@ -44,10 +48,19 @@ test("Create a developer collective, then create Tool as the creator of the coll
       title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      version: "0.1.0",
+      icon: await createIcon(alice.cells[0]),
+      source: {
+        type: "WebHappUrl",
+        url: "https://example.org/releases/tool.webhapp",
+      },
+      hashes: [
+        {
+          artifact: { type: "WebHapp" },
+          algorithm: "Sha256",
+          digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+        },
+      ],
       changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
@@ -89,10 +102,19 @@ test("Create a developer collective, then try to create a Tool for that collecti
         title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+        version: "0.1.0",
+        icon: await createIcon(bob.cells[0]),
+        source: {
+          type: "WebHappUrl",
+          url: "https://example.org/releases/tool.webhapp",
+        },
+        hashes: [
+          {
+            artifact: { type: "WebHapp" },
+            algorithm: "Sha256",
+            digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+          },
+        ],
         changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
@@ -133,7 +155,7 @@ test("Create a developer collective, then create a ContributorPermission, then c
     );
 
     // Alice creates a ContributorPermission for Bob that never expires
-    const contributorPermission: Record = await createContributorPermission(alice.cells[0], {
+    const contributorPermission: Record = await createContributorPermission(alice, bob, {
       for_agent: bob.agentPubKey,
       for_collective: collectiveRecord.signed_action.hashed.hash,
       expiry: undefined,
@@ -148,10 +170,19 @@ test("Create a developer collective, then create a ContributorPermission, then c
       title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      version: "0.1.0",
+      icon: await createIcon(bob.cells[0]),
+      source: {
+        type: "WebHappUrl",
+        url: "https://example.org/releases/tool.webhapp",
+      },
+      hashes: [
+        {
+          artifact: { type: "WebHapp" },
+          algorithm: "Sha256",
+          digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+        },
+      ],
       changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
@@ -186,7 +217,7 @@ test("Create a Tool with expiring but valid permission", async () => {
     );
 
     // Alice creates a ContributorPermission for Bob that never expires
-    const contributorPermission: Record = await createContributorPermission(alice.cells[0], {
+    const contributorPermission: Record = await createContributorPermission(alice, bob, {
       for_agent: bob.agentPubKey,
       for_collective: collectiveRecord.signed_action.hashed.hash,
       expiry: (Date.now() * 1000) + 1e9,
@@ -201,10 +232,19 @@ test("Create a Tool with expiring but valid permission", async () => {
       title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-      hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      version: "0.1.0",
+      icon: await createIcon(bob.cells[0]),
+      source: {
+        type: "WebHappUrl",
+        url: "https://example.org/releases/tool.webhapp",
+      },
+      hashes: [
+        {
+          artifact: { type: "WebHapp" },
+          algorithm: "Sha256",
+          digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+        },
+      ],
       changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
       deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
@@ -239,7 +279,7 @@ test("Try to create a Tool with expired permission", async () => {
     );
 
     // Alice creates a ContributorPermission for Bob that never expires
-    const contributorPermission: Record = await createContributorPermission(alice.cells[0], {
+    const contributorPermission: Record = await createContributorPermission(alice, bob, {
       for_agent: bob.agentPubKey,
       for_collective: collectiveRecord.signed_action.hashed.hash,
       expiry: (Date.now() * 1000) - 1e9,
@@ -255,10 +295,19 @@ test("Try to create a Tool with expired permission", async () => {
         title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         subtitle: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        version: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        icon: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        source: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-        hashes: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+        version: "0.1.0",
+        icon: await createIcon(bob.cells[0]),
+        source: {
+          type: "WebHappUrl",
+          url: "https://example.org/releases/tool.webhapp",
+        },
+        hashes: [
+          {
+            artifact: { type: "WebHapp" },
+            algorithm: "Sha256",
+            digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+          },
+        ],
         changelog: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         meta_data: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
         deprecation: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
@@ -272,6 +321,381 @@ test("Try to create a Tool with expired permission", async () => {
   });
 });
 
+test("Try to create a Tool with an untyped legacy source", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+
+    // Alice tries to create a Tool with a plain JSON string as source
+    try {
+      await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+        developer_collective: collectiveRecord.signed_action.hashed.hash,
+        permission_hash: collectiveRecord.signed_action.hashed.hash,
+        source: JSON.stringify({ type: "https", url: "https://example.org/tool.webhapp" }),
+      }));
+    } catch (e) {
+      if (e.toString().includes("Untyped Tool sources are no longer supported")) {
+        return;
+      }
+    }
+    assert.fail("Creation of a Tool with an untyped source should fail.");
+  });
+});
+
+test("Create a Tool with artifact hashes and get them back parsed, then fail to create one with a malformed digest", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+
+    const hashes = [
+      {
+        artifact: { type: "WebHapp" },
+        algorithm: "Sha256",
+        digest: "a3f1c7d8e9b0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aa",
+      },
+      {
+        artifact: { type: "Dna", role_name: "tools" },
+        algorithm: "HolochainDnaHash",
+        digest: "uhC0kXtGnCzUGrJwVsmVwaQR4WJzgLlpMo9TDyGeMyy2CUHjhXcYb",
+      },
+    ];
+
+    // Alice creates a Tool
+    const record: Record = await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+      developer_collective: collectiveRecord.signed_action.hashed.hash,
+      permission_hash: collectiveRecord.signed_action.hashed.hash,
+      hashes,
+    }));
+
+    const artifactHashes = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_tool_artifact_hashes",
+      payload: record.signed_action.hashed.hash,
+    });
+    assert.deepEqual(artifactHashes, hashes);
+
+    // Alice tries to create a Tool with a truncated digest
+    try {
+      await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+        developer_collective: collectiveRecord.signed_action.hashed.hash,
+        permission_hash: collectiveRecord.signed_action.hashed.hash,
+        hashes: [{ ...hashes[0], digest: "a3f1c7d8" }],
+      }));
+    } catch (e) {
+      if (e.toString().includes("Sha256 digest must be 64 hex characters long.")) {
+        return;
+      }
+    }
+    assert.fail("Creation of a Tool with a malformed digest should fail.");
+  });
+});
+
+test("Tool versions may not decrease and the latest Tool by version is ordered by semver", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Alice creates a Tool with version 0.2.0
+    const record: Record = await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+      developer_collective: collectiveHash,
+      permission_hash: collectiveHash,
+      version: "0.2.0",
+    }));
+    const originalToolHash = record.signed_action.hashed.hash;
+
+    const updateTool = async (previousToolHash, version) => alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: originalToolHash,
+        previous_tool_hash: previousToolHash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], { permission_hash: collectiveHash, version }),
+      },
+    });
+
+    // Alice publishes 0.3.0 and afterwards re-publishes a patch of 0.2.0
+    await updateTool(originalToolHash, "0.3.0");
+    await updateTool(originalToolHash, "0.2.1");
+
+    const latestByVersion: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_latest_tool_by_version",
+      payload: originalToolHash,
+    });
+    assert.equal((decode((latestByVersion.entry as any).Present.entry) as any).version, "0.3.0");
+
+    // Alice tries to decrease the version
+    try {
+      await updateTool(originalToolHash, "0.1.9");
+    } catch (e) {
+      if (e.toString().includes("The version of a Tool may not be decreased")) {
+        return;
+      }
+    }
+    assert.fail("Decreasing the version of a Tool should fail.");
+  });
+});
+
+test("Updates of stale Tool revisions are checked against the revisions they descend from", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Alice creates a Tool with version 1.0.0 and publishes 2.0.0
+    const record: Record = await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+      developer_collective: collectiveHash,
+      permission_hash: collectiveHash,
+      version: "1.0.0",
+    }));
+    const originalToolHash = record.signed_action.hashed.hash;
+
+    const updateTool = async (previousToolHash, version): Promise<Record> => alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: originalToolHash,
+        previous_tool_hash: previousToolHash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], { permission_hash: collectiveHash, version }),
+      },
+    });
+    await updateTool(originalToolHash, "2.0.0");
+
+    // Updating the stale 1.0.0 revision only needs to keep up with 1.0.0, not with 2.0.0
+    const patchRecord = await updateTool(originalToolHash, "1.0.1");
+    assert.ok(patchRecord);
+
+    const latestByVersion: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "get_latest_tool_by_version",
+      payload: originalToolHash,
+    });
+    assert.equal((decode((latestByVersion.entry as any).Present.entry) as any).version, "2.0.0");
+
+    // Updating the patch revision is checked against the patch, not against the original
+    try {
+      await updateTool(patchRecord.signed_action.hashed.hash, "1.0.0");
+      assert.fail("Decreasing the version of the patch revision should fail.");
+    } catch (e) {
+      if (!e.toString().includes("The version of a Tool may not be decreased (from 1.0.1 to 1.0.0)")) {
+        assert.fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
+
+test("Beta releases of a Tool are only returned to agents following the beta channel", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    // Alice creates a stable Tool release
+    const record: Record = await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+      developer_collective: collectiveHash,
+      permission_hash: collectiveHash,
+      version: "1.0.0",
+      channel: "Stable",
+    }));
+    const originalToolHash = record.signed_action.hashed.hash;
+
+    // Alice publishes a pre-release to the beta channel
+    const betaRecord: Record = await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: originalToolHash,
+        previous_tool_hash: originalToolHash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+          version: "1.1.0-beta.1",
+          channel: "Beta",
+        }),
+      },
+    });
+
+    const getLatestForChannel = async (channel) => {
+      const latest: Record = await alice.cells[0].callZome({
+        zome_name: "library",
+        fn_name: "get_latest_tool_for_channel",
+        payload: { original_tool_hash: originalToolHash, channel },
+      });
+      return (decode((latest.entry as any).Present.entry) as any).version;
+    };
+
+    assert.equal(await getLatestForChannel("Stable"), "1.0.0");
+    assert.equal(await getLatestForChannel("Beta"), "1.1.0-beta.1");
+    assert.equal(await getLatestForChannel("Nightly"), "1.1.0-beta.1");
+
+    // Versions must not decrease within a channel only, so a stable patch release can
+    // follow the beta release
+    await alice.cells[0].callZome({
+      zome_name: "library",
+      fn_name: "update_tool",
+      payload: {
+        original_tool_hash: originalToolHash,
+        previous_tool_hash: betaRecord.signed_action.hashed.hash,
+        updated_tool: await sampleToolUpdate(alice.cells[0], {
+          permission_hash: collectiveHash,
+          version: "1.0.1",
+          channel: "Stable",
+        }),
+      },
+    });
+
+    assert.equal(await getLatestForChannel("Stable"), "1.0.1");
+    assert.equal(await getLatestForChannel("Beta"), "1.1.0-beta.1");
+  });
+});
+
+test("Try to create Tools with a too long title or an icon that is not an Icon entry", async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + "/../workdir/tools-library.happ";
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, _bob] = await scenario.addPlayersWithApps([
+      appSource,
+      appSource,
+    ]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a devloper collective
+    const collectiveRecord: Record = await createDeveloperCollective(
+      alice.cells[0]
+    );
+    const collectiveHash = collectiveRecord.signed_action.hashed.hash;
+
+    const invalidTools = [
+      { partialTool: { title: "a".repeat(101) }, error: "Title may be no longer than 100 characters." },
+      { partialTool: { icon: "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAAB" }, error: "Icon must be the EntryHash of an Icon entry." },
+    ];
+
+    for (const { partialTool, error } of invalidTools) {
+      let failed = false;
+      try {
+        await createTool(alice.cells[0], await sampleTool(alice.cells[0], {
+          developer_collective: collectiveHash,
+          permission_hash: collectiveHash,
+          ...partialTool,
+        }));
+      } catch (e) {
+        failed = e.toString().includes(error);
+      }
+      assert.isTrue(failed, `Creating a Tool should fail with "${error}"`);
+    }
+  });
+});
+
 // test("create and read Tool", async () => {
 //   await runScenario(async (scenario) => {
 //     // Construct proper paths for your app.